@@ -0,0 +1,83 @@
+/// Seed polyhedron a Conway expression starts from
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Seed {
+    Tetrahedron,
+    Cube,
+    Octahedron,
+    Dodecahedron,
+    Icosahedron,
+    Prism(usize),
+    Antiprism(usize),
+    Pyramid(usize),
+    Cupola(usize),
+    Anticupola(usize),
+    Johnson(usize),
+}
+
+/// Conway operator letters known to the parser
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum OperatorKind {
+    Ambo,
+    Bevel,
+    Chamfer,
+    Dual,
+    Expand,
+    Gyro,
+    Join,
+    Kis,
+    Loft,
+    Meta,
+    Needle,
+    Ortho,
+    Propeller,
+    Quinto,
+    Snub,
+    Truncate,
+    Whirl,
+    Cross,
+    Zip,
+}
+
+impl OperatorKind {
+    pub fn from_letter(letter: char) -> Option<OperatorKind> {
+        match letter {
+            'a' => Some(OperatorKind::Ambo),
+            'b' => Some(OperatorKind::Bevel),
+            'c' => Some(OperatorKind::Chamfer),
+            'd' => Some(OperatorKind::Dual),
+            'e' => Some(OperatorKind::Expand),
+            'g' => Some(OperatorKind::Gyro),
+            'j' => Some(OperatorKind::Join),
+            'k' => Some(OperatorKind::Kis),
+            'l' => Some(OperatorKind::Loft),
+            'm' => Some(OperatorKind::Meta),
+            'n' => Some(OperatorKind::Needle),
+            'o' => Some(OperatorKind::Ortho),
+            'p' => Some(OperatorKind::Propeller),
+            'q' => Some(OperatorKind::Quinto),
+            's' => Some(OperatorKind::Snub),
+            't' => Some(OperatorKind::Truncate),
+            'w' => Some(OperatorKind::Whirl),
+            'x' => Some(OperatorKind::Cross),
+            'z' => Some(OperatorKind::Zip),
+            _ => None
+        }
+    }
+}
+
+/// One operator of an expression, with its optional numeric parameter
+/// and the column it was found at
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Operator {
+    pub kind: OperatorKind,
+    pub parameter: Option<usize>,
+    pub column: usize,
+}
+
+/// Parsed Conway expression. The operators are stored as written,
+/// so they are applied to the seed from last to first.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Expression {
+    pub operators: Vec<Operator>,
+    pub seed: Seed,
+}
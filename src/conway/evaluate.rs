@@ -0,0 +1,113 @@
+use super::{Expression, Operator, OperatorKind, Seed};
+use super::super::geometry::{Mesh, MeshError, SeedScale};
+use super::super::operations::{ChamberPattern, Operator as MeshOperator, Ambo, Kis, Truncate, Join, Ortho, Expand, Gyro, Snub, Handedness};
+use super::super::operations::{Chamfer, Bevel, Meta, Needle, Zip, Loft, Whirl, Propeller, Quinto};
+use std::fmt;
+
+/// Error while evaluating a parsed expression
+#[derive(PartialEq, Clone, Debug)]
+pub enum EvaluateError {
+    /// The operator is known to the parser but not implemented
+    UnsupportedOperator(Operator),
+    /// The operator does not take the given number
+    UnexpectedParameter(Operator),
+    /// The seed is known to the parser but not implemented
+    UnsupportedSeed(Seed),
+    /// An operator could not build its faces on the mesh
+    Mesh(MeshError),
+}
+
+impl fmt::Display for EvaluateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvaluateError::UnsupportedOperator(operator) => {
+                write!(f, "Unsupported operator {:?} at column {}", operator.kind, operator.column)
+            }
+            EvaluateError::UnexpectedParameter(operator) => {
+                write!(f, "Operator {:?} at column {} takes no number", operator.kind, operator.column)
+            }
+            EvaluateError::UnsupportedSeed(seed) => write!(f, "Unsupported seed {:?}", seed),
+            EvaluateError::Mesh(error) => write!(f, "Operator failed: {}", error)
+        }
+    }
+}
+
+impl std::error::Error for EvaluateError {}
+
+impl From<MeshError> for EvaluateError {
+    fn from(error: MeshError) -> Self {
+        EvaluateError::Mesh(error)
+    }
+}
+
+impl Expression {
+    /// Builds the seed with unit circumradius and applies the operators right to left
+    pub fn evaluate(&self) -> Result<Mesh, EvaluateError> {
+        let mut mesh = build_seed(self.seed)?;
+        for operator in self.operators.iter().rev() {
+            mesh = apply(&mesh, operator)?;
+        }
+
+        Ok(mesh)
+    }
+}
+
+fn build_seed(seed: Seed) -> Result<Mesh, EvaluateError> {
+    let scale = SeedScale::UnitCircumradius;
+    match seed {
+        Seed::Tetrahedron => Ok(Mesh::tetrahedron(scale)),
+        Seed::Cube => Ok(Mesh::cube(scale)),
+        Seed::Octahedron => Ok(Mesh::octahedron(scale)),
+        Seed::Dodecahedron => Ok(Mesh::dodecahedron(scale)),
+        Seed::Icosahedron => Ok(Mesh::icosahedron(scale)),
+        Seed::Prism(n) => Mesh::prism(n, scale).ok_or(EvaluateError::UnsupportedSeed(seed)),
+        Seed::Antiprism(n) => Mesh::antiprism(n, scale).ok_or(EvaluateError::UnsupportedSeed(seed)),
+        Seed::Pyramid(n) => Mesh::pyramid(n, scale).ok_or(EvaluateError::UnsupportedSeed(seed)),
+        Seed::Cupola(n) => Mesh::cupola(n, scale).ok_or(EvaluateError::UnsupportedSeed(seed)),
+        Seed::Anticupola(n) => Mesh::anticupola(n, scale).ok_or(EvaluateError::UnsupportedSeed(seed)),
+        Seed::Johnson(n) => Mesh::johnson(n, scale).ok_or(EvaluateError::UnsupportedSeed(seed))
+    }
+}
+
+fn apply(mesh: &Mesh, operator: &Operator) -> Result<Mesh, EvaluateError> {
+    let parameter = operator.parameter;
+    let result = match operator.kind {
+        OperatorKind::Kis => Kis::compute(mesh, parameter, 0.0)?,
+        OperatorKind::Truncate => Truncate::compute(mesh, parameter, 1.0 / 3.0)?,
+        kind => {
+            let operation = unary_operation(kind).ok_or(EvaluateError::UnsupportedOperator(*operator))?;
+            if parameter.is_some() {
+                return Err(EvaluateError::UnexpectedParameter(*operator));
+            }
+            operation(mesh)?
+        }
+    };
+
+    Ok(result)
+}
+
+type UnaryOperation = fn(&Mesh) -> Result<Mesh, MeshError>;
+
+// operations without a numeric parameter, chiral ones are right handed
+// and chamfer and loft use their default depth and height
+fn unary_operation(kind: OperatorKind) -> Option<UnaryOperation> {
+    match kind {
+        OperatorKind::Dual => Some(|mesh| ChamberPattern::dual().apply(mesh)),
+        OperatorKind::Ambo => Some(<Mesh as Ambo>::compute),
+        OperatorKind::Join => Some(<Mesh as Join>::compute),
+        OperatorKind::Ortho => Some(<Mesh as Ortho>::compute),
+        OperatorKind::Expand => Some(<Mesh as Expand>::compute),
+        OperatorKind::Gyro => Some(|mesh| Gyro::compute(mesh, Handedness::Right)),
+        OperatorKind::Snub => Some(|mesh| Snub::compute(mesh, Handedness::Right)),
+        OperatorKind::Chamfer => Some(|mesh| Chamfer::compute(mesh, 1.0 / 3.0)),
+        OperatorKind::Bevel => Some(<Mesh as Bevel>::compute),
+        OperatorKind::Meta => Some(<Mesh as Meta>::compute),
+        OperatorKind::Needle => Some(<Mesh as Needle>::compute),
+        OperatorKind::Zip => Some(<Mesh as Zip>::compute),
+        OperatorKind::Loft => Some(|mesh| Loft::compute(mesh, 1.0 / 3.0)),
+        OperatorKind::Whirl => Some(|mesh| Whirl::compute(mesh, Handedness::Right)),
+        OperatorKind::Propeller => Some(|mesh| Propeller::compute(mesh, Handedness::Right)),
+        OperatorKind::Quinto => Some(<Mesh as Quinto>::compute),
+        _ => None
+    }
+}
@@ -0,0 +1,8 @@
+pub use self::ast::{Expression, Operator, OperatorKind, Seed};
+mod ast;
+
+pub use self::parser::{parse, ParseError};
+mod parser;
+
+pub use self::evaluate::EvaluateError;
+mod evaluate;
@@ -0,0 +1,161 @@
+use super::{Expression, Operator, OperatorKind, Seed};
+use std::fmt;
+
+/// Error while parsing a Conway expression, columns start at 1
+#[derive(PartialEq, Clone, Debug)]
+pub struct ParseError {
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(column: usize, token: &str, message: &str) -> ParseError {
+        ParseError {
+            column,
+            token: token.to_string(),
+            message: message.to_string()
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at column {}: '{}'", self.message, self.column, self.token)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(PartialEq, Clone, Debug)]
+enum Token {
+    Letter(char),
+    Number(String),
+}
+
+// a token together with the column it starts at
+type Spanned = (Token, usize);
+
+fn tokenize(notation: &str) -> Result<Vec<Spanned>, ParseError> {
+    let mut tokens: Vec<Spanned> = Vec::new();
+    let chars: Vec<char> = notation.chars().collect();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let column = i + 1;
+        let c = chars[i];
+        if c.is_ascii_digit() {
+            let mut digits = String::new();
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                digits.push(chars[i]);
+                i += 1;
+            }
+            tokens.push((Token::Number(digits), column));
+            continue;
+        }
+        if !c.is_ascii_alphabetic() {
+            return Err(ParseError::new(column, &c.to_string(), "Unexpected character"));
+        }
+        tokens.push((Token::Letter(c), column));
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+/// Parses a Conway notation string like "dakD" or "k5tP7" into an expression.
+/// Operators are lower case letters with an optional number, the last
+/// token has to be an upper case seed letter.
+pub fn parse(notation: &str) -> Result<Expression, ParseError> {
+    let tokens = tokenize(notation)?;
+    let mut operators: Vec<Operator> = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let (token, column) = tokens[i].clone();
+        let letter = match token {
+            Token::Number(digits) => {
+                return Err(ParseError::new(column, &digits, "Number without operator"));
+            }
+            Token::Letter(letter) => letter
+        };
+
+        // optional number following the letter
+        let mut parameter: Option<(usize, usize, String)> = None;
+        if let Some((Token::Number(digits), number_column)) = tokens.get(i + 1) {
+            let value = digits.parse::<usize>()
+                .map_err(|_| ParseError::new(*number_column, digits, "Number out of range"))?;
+            parameter = Some((value, *number_column, digits.clone()));
+            i += 1;
+        }
+        i += 1;
+
+        if letter.is_ascii_uppercase() {
+            let seed = parse_seed(letter, column, parameter)?;
+            if i < tokens.len() {
+                let (token, column) = &tokens[i];
+                let text = match token {
+                    Token::Letter(letter) => letter.to_string(),
+                    Token::Number(digits) => digits.clone()
+                };
+                return Err(ParseError::new(*column, &text, "Unexpected token after seed"));
+            }
+            return Ok(Expression { operators, seed });
+        }
+
+        match OperatorKind::from_letter(letter) {
+            None => return Err(ParseError::new(column, &letter.to_string(), "Unknown operator")),
+            Some(kind) => operators.push(Operator {
+                kind,
+                parameter: parameter.map(|(value, _, _)| value),
+                column
+            })
+        }
+    }
+
+    let column = notation.chars().count() + 1;
+    Err(ParseError::new(column, "", "Missing seed"))
+}
+
+fn parse_seed(letter: char, column: usize, parameter: Option<(usize, usize, String)>) -> Result<Seed, ParseError> {
+    let token = letter.to_string();
+    let platonic = match letter {
+        'T' => Some(Seed::Tetrahedron),
+        'C' => Some(Seed::Cube),
+        'O' => Some(Seed::Octahedron),
+        'D' => Some(Seed::Dodecahedron),
+        'I' => Some(Seed::Icosahedron),
+        _ => None
+    };
+    if let Some(seed) = platonic {
+        return match parameter {
+            None => Ok(seed),
+            Some((_, number_column, digits)) => {
+                Err(ParseError::new(number_column, &digits, "Seed takes no number"))
+            }
+        };
+    }
+
+    let family: fn(usize) -> Seed = match letter {
+        'P' => Seed::Prism,
+        'A' => Seed::Antiprism,
+        'Y' => Seed::Pyramid,
+        'U' => Seed::Cupola,
+        'V' => Seed::Anticupola,
+        'J' => Seed::Johnson,
+        _ => return Err(ParseError::new(column, &token, "Unknown seed"))
+    };
+
+    match parameter {
+        None => Err(ParseError::new(column, &token, "Seed needs a number")),
+        Some((value, number_column, digits)) => {
+            if letter == 'J' && value < 1 {
+                return Err(ParseError::new(number_column, &digits, "Johnson solids start at 1"));
+            }
+            if letter != 'J' && value < 3 {
+                return Err(ParseError::new(number_column, &digits, "Seed needs at least 3 sides"));
+            }
+            Ok(family(value))
+        }
+    }
+}
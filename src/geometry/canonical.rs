@@ -0,0 +1,153 @@
+use super::{Mesh, Point, VertexIndex, FaceIndex};
+
+const TANGENT_FACTOR: f64 = 0.5;
+const PLANAR_FACTOR: f64 = 0.2;
+
+/// State of the mesh when an iterative relaxation stopped
+#[derive(Copy, Clone, Debug)]
+pub struct CanonicalReport {
+    pub iterations: usize,
+    /// The largest vertex movement of the last iteration fell below the tolerance
+    pub converged: bool,
+    /// Largest vertex movement of the last iteration
+    pub max_change: f64,
+    /// Largest deviation of an edge's distance from the origin from one
+    pub tangency_error: f64,
+    /// Distance of the centroid of the edge tangency points from the origin
+    pub centroid_error: f64,
+    /// Largest face planarity error
+    pub planarity_error: f64,
+}
+
+// Hart's canonicalization: every iteration pulls the edges towards the unit
+// sphere, moves the centroid of the tangency points back to the origin and
+// pushes the vertices towards the planes of their faces
+impl Mesh {
+    /// Iterates until no vertex moves more than `tolerance` or `max_iterations` is reached.
+    /// Only the vertex positions change, the connectivity is kept.
+    pub fn canonicalize(&mut self, max_iterations: usize, tolerance: f64) -> CanonicalReport {
+        let mut iterations = 0;
+        let mut max_change = 0.0;
+        while iterations < max_iterations {
+            let before = self.vertex_positions();
+            self.tangentify();
+            self.recenter();
+            self.planarize_step(PLANAR_FACTOR);
+            iterations += 1;
+
+            max_change = self.max_change_from(&before);
+            if max_change < tolerance {
+                break;
+            }
+        }
+
+        self.canonical_report(iterations, max_change, tolerance)
+    }
+
+    pub(super) fn canonical_report(&self, iterations: usize, max_change: f64, tolerance: f64) -> CanonicalReport {
+        let tangents = self.edge_tangent_points();
+        let centroid = match tangents.is_empty() {
+            true => Point::new(),
+            false => tangents.iter().fold(Point::new(), |sum, p| sum + *p) / tangents.len() as f64
+        };
+
+        CanonicalReport {
+            iterations,
+            converged: max_change < tolerance,
+            max_change,
+            tangency_error: tangents.iter().map(|p| (p.length() - 1.0).abs()).fold(0.0, f64::max),
+            centroid_error: centroid.length(),
+            planarity_error: (0..self.face_count())
+                .map(|i| self.face_planarity_error(FaceIndex::new(i as u32)))
+                .fold(0.0, f64::max)
+        }
+    }
+
+    fn tangentify(&mut self) {
+        let mut positions = self.vertex_positions();
+        for (start, end) in self.edge_vertices() {
+            let tangent = tangent_point(positions[start.index as usize], positions[end.index as usize]);
+            let adjustment = tangent * (TANGENT_FACTOR * (1.0 - tangent.length()));
+            positions[start.index as usize] += adjustment;
+            positions[end.index as usize] += adjustment;
+        }
+
+        self.set_vertex_positions(&positions);
+    }
+
+    fn recenter(&mut self) {
+        let tangents = self.edge_tangent_points();
+        if tangents.is_empty() {
+            return;
+        }
+
+        let centroid = tangents.iter().fold(Point::new(), |sum, p| sum + *p) / tangents.len() as f64;
+        let positions: Vec<Point> = self.vertex_positions().iter().map(|p| *p - centroid).collect();
+        self.set_vertex_positions(&positions);
+    }
+
+    // moves every vertex by `factor` of its distances to the planes of its faces
+    fn planarize_step(&mut self, factor: f64) {
+        let mut positions = self.vertex_positions();
+        for i in 0..self.face_count() {
+            let face = FaceIndex::new(i as u32);
+            let vertices: Vec<VertexIndex> = match self.face_vertices(face) {
+                Err(_) => continue,
+                Ok(vertices) => vertices.collect()
+            };
+
+            let normal = self.face_normal(face);
+            let center = vertices.iter()
+                .fold(Point::new(), |sum, v| sum + self.vertex(*v).location) / vertices.len() as f64;
+            for v in vertices {
+                let location = self.vertex(v).location;
+                positions[v.index as usize] += normal * (factor * normal.dot(center - location));
+            }
+        }
+
+        self.set_vertex_positions(&positions);
+    }
+
+    pub(super) fn vertex_positions(&self) -> Vec<Point> {
+        (0..self.vertex_count())
+            .map(|i| self.vertex(VertexIndex::new(i as u32)).location)
+            .collect()
+    }
+
+    pub(super) fn set_vertex_positions(&mut self, positions: &[Point]) {
+        for (i, position) in positions.iter().enumerate() {
+            self.set_vertex_position(VertexIndex::new(i as u32), *position);
+        }
+    }
+
+    pub(super) fn max_change_from(&self, before: &[Point]) -> f64 {
+        self.vertex_positions().iter()
+            .zip(before.iter())
+            .map(|(after, before)| after.distance(*before))
+            .fold(0.0, f64::max)
+    }
+
+    // start and end vertex of every used edge pair
+    pub(super) fn edge_vertices(&self) -> Vec<(VertexIndex, VertexIndex)> {
+        self.edges()
+            .filter_map(|edge| self.edge_endpoints(edge).ok())
+            .collect()
+    }
+
+    fn edge_tangent_points(&self) -> Vec<Point> {
+        self.edge_vertices().iter()
+            .map(|(start, end)| tangent_point(self.vertex(*start).location, self.vertex(*end).location))
+            .collect()
+    }
+}
+
+// point on the line through a and b closest to the origin
+fn tangent_point(a: Point, b: Point) -> Point {
+    let direction = b - a;
+    let length = direction.dot(direction);
+    if length < f64::EPSILON {
+        return a;
+    }
+
+    a - direction * (direction.dot(a) / length)
+}
@@ -0,0 +1,82 @@
+use super::constants::{UNSET_VALUE};
+use super::{Mesh, Point, VertexIndex, HalfEdgeIndex, MeshError, UnsetValue};
+
+/// An undirected edge, the half-edge pair stored at 2k and 2k + 1
+#[derive(PartialEq, Copy, Clone, PartialOrd, Debug)]
+pub struct EdgeIndex {
+    pub index: u32
+}
+
+impl UnsetValue for EdgeIndex {
+    fn unset() -> EdgeIndex {
+        EdgeIndex { index: UNSET_VALUE}
+    }
+
+    fn is_unset(&self) -> bool {
+        *self == EdgeIndex::unset()
+    }
+}
+
+impl EdgeIndex {
+    pub fn new(index: u32) -> EdgeIndex {
+        EdgeIndex {index}
+    }
+
+    pub fn increment(&mut self) {
+        self.index += 1;
+    }
+
+    /// The edge a half-edge belongs to
+    pub fn from_half_edge(index: HalfEdgeIndex) -> EdgeIndex {
+        match index.is_unset() {
+            true => EdgeIndex::unset(),
+            false => EdgeIndex::new(index.index / 2)
+        }
+    }
+
+    /// The half-edge at 2k, its pair is at 2k + 1
+    pub fn half_edge(&self) -> HalfEdgeIndex {
+        match self.is_unset() {
+            true => HalfEdgeIndex::unset(),
+            false => HalfEdgeIndex::new(2 * self.index)
+        }
+    }
+}
+
+// All things related to undirected edges
+impl Mesh {
+    /// Number of used edges, half-edge pairs removed before `compact` are not counted
+    pub fn edge_count(&self) -> usize {
+        self.edges().count()
+    }
+
+    /// All used edges, their indices have gaps where pairs were removed
+    pub fn edges(&self) -> impl Iterator<Item = EdgeIndex> + '_ {
+        (0..self.half_edge_count() / 2)
+            .map(|i| EdgeIndex::new(i as u32))
+            .filter(move |edge| !self.half_edge(edge.half_edge()).is_unused())
+    }
+
+    /// Start and end vertex of the half-edge at 2k
+    pub fn edge_endpoints(&self, index: EdgeIndex) -> Result<(VertexIndex, VertexIndex), MeshError> {
+        if index.index >= (self.half_edge_count() / 2) as u32 {
+            return Err(MeshError::IndexOutOfRange(index.index));
+        }
+
+        let half_edge = index.half_edge();
+        if self.half_edge(half_edge).is_unused() {
+            return Err(MeshError::UnusedEdge(index));
+        }
+        Ok((self.half_edge(half_edge).start_vertex, self.find_end_vertex_index(half_edge)))
+    }
+
+    pub fn edge_midpoint(&self, index: EdgeIndex) -> Result<Point, MeshError> {
+        let (start, end) = self.edge_endpoints(index)?;
+        Ok(self.vertex(start).location.lerp(self.vertex(end).location, 0.5))
+    }
+
+    pub fn edge_length(&self, index: EdgeIndex) -> Result<f64, MeshError> {
+        let (start, end) = self.edge_endpoints(index)?;
+        Ok(self.vertex(start).location.distance(self.vertex(end).location))
+    }
+}
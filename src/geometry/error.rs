@@ -0,0 +1,54 @@
+use super::{VertexIndex, HalfEdgeIndex, EdgeIndex, FaceIndex};
+use std::fmt;
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum MeshError {
+    /// A face needs at least three distinct vertices
+    DegenerateFace,
+    /// The index points past the end of its collection or is unset
+    IndexOutOfRange(u32),
+    /// The half-edge already has a face, another one would make the edge non-manifold
+    NonManifoldEdge(HalfEdgeIndex),
+    /// The vertex has no free boundary gap to attach a new face to
+    NonManifoldVertex(VertexIndex),
+    /// The vertex has no half-edges, so there is nothing to circulate
+    IsolatedVertex(VertexIndex),
+    /// Only one half of the half-edge pair is in use
+    UnpairedHalfEdge(HalfEdgeIndex),
+    /// The edge was removed and is only kept in storage until `compact`
+    UnusedEdge(EdgeIndex),
+    /// Following the half-edge links from this half-edge never returns to it
+    BrokenLoop(HalfEdgeIndex),
+    /// The measurement needs a closed mesh but this half-edge has no face on one side
+    BoundaryEdge(HalfEdgeIndex),
+    /// The mesh encloses no volume, so no centroid can be found
+    ZeroVolume,
+    /// The plane of the face passes through the center of reciprocation
+    PlaneThroughCenter(FaceIndex),
+    /// A geodesic subdivision needs a frequency with m + n > 0
+    ZeroFrequency,
+    /// A truncation cuts each edge at a ratio greater than 0 and at most 0.5
+    RatioOutOfRange,
+}
+
+impl fmt::Display for MeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshError::DegenerateFace => write!(f, "A face needs at least three distinct vertices"),
+            MeshError::IndexOutOfRange(index) => write!(f, "Index {} is out of range", index),
+            MeshError::NonManifoldEdge(index) => write!(f, "Half-edge {} already has a face", index.index),
+            MeshError::NonManifoldVertex(index) => write!(f, "Vertex {} would become non-manifold", index.index),
+            MeshError::IsolatedVertex(index) => write!(f, "Vertex {} has no half-edges", index.index),
+            MeshError::UnpairedHalfEdge(index) => write!(f, "Half-edge {} is used without its pair", index.index),
+            MeshError::UnusedEdge(index) => write!(f, "Edge {} is not in use", index.index),
+            MeshError::BrokenLoop(index) => write!(f, "Half-edge loop from {} does not close", index.index),
+            MeshError::BoundaryEdge(index) => write!(f, "Half-edge {} lies on a boundary", index.index),
+            MeshError::ZeroVolume => write!(f, "Mesh encloses no volume"),
+            MeshError::PlaneThroughCenter(index) => write!(f, "Plane of face {} passes through the center", index.index),
+            MeshError::ZeroFrequency => write!(f, "Geodesic frequency needs m + n > 0"),
+            MeshError::RatioOutOfRange => write!(f, "Truncation ratio has to lie in (0, 0.5]")
+        }
+    }
+}
+
+impl std::error::Error for MeshError {}
@@ -19,7 +19,7 @@ impl UnsetValue for FaceIndex {
 
 impl FaceIndex {
     pub fn new(index: u32) -> FaceIndex {
-        FaceIndex {index: index}
+        FaceIndex {index}
     }
 
     pub fn increment(&mut self) {
@@ -0,0 +1,135 @@
+use super::constants::{UNSET_VALUE};
+use super::{Mesh, VertexIndex, HalfEdgeIndex, FaceIndex, HalfEdgeCollection, UnsetValue};
+use std::ops::Index;
+
+#[derive(PartialEq, Copy, Clone, PartialOrd, Debug)]
+pub struct FlagIndex {
+    pub index: u32
+}
+
+impl UnsetValue for FlagIndex {
+    fn unset() -> FlagIndex {
+        FlagIndex { index: UNSET_VALUE}
+    }
+
+    fn is_unset(&self) -> bool {
+        *self == FlagIndex::unset()
+    }
+}
+
+impl FlagIndex {
+    pub fn new(index: u32) -> FlagIndex {
+        FlagIndex {index}
+    }
+
+    pub fn increment(&mut self) {
+        self.index += 1;
+    }
+}
+
+/// A chamber of the barycentric subdivision: a vertex lying on an edge lying on a face.
+/// The edge is stored as the half-edge adjacent to the face.
+#[derive(Copy, Clone, Debug)]
+pub struct Flag {
+    pub vertex: VertexIndex,
+    pub half_edge: HalfEdgeIndex,
+    pub face: FaceIndex
+}
+
+/// All flags of a mesh together with the three involutions
+/// σ0 (swap vertex), σ1 (swap edge) and σ2 (swap face).
+/// σ2 is unset for flags on a boundary edge.
+#[derive(Debug)]
+pub struct FlagGraph {
+    flags: Vec<Flag>,
+    sigmas: Vec<[FlagIndex; 3]>
+}
+
+impl Index<FlagIndex> for FlagGraph {
+    type Output = Flag;
+
+    fn index(&self, index: FlagIndex) -> &Self::Output {
+        &self.flags[index.index as usize]
+    }
+}
+
+impl FlagGraph {
+    pub fn new(mesh: &Mesh) -> FlagGraph {
+        // every half-edge with a face carries two flags, one per end vertex
+        let mut first_flag = vec![FlagIndex::unset(); mesh.half_edge_count()];
+        let mut flags: Vec<Flag> = Vec::new();
+
+        for f in 0..mesh.face_count() {
+            let face = FaceIndex::new(f as u32);
+            if let Ok(edges) = mesh.face_edges(face) {
+                for edge in edges {
+                    first_flag[edge.index as usize] = FlagIndex::new(flags.len() as u32);
+                    flags.push(Flag { vertex: mesh.half_edge(edge).start_vertex, half_edge: edge, face });
+                    flags.push(Flag { vertex: mesh.find_end_vertex_index(edge), half_edge: edge, face });
+                }
+            }
+        }
+
+        let flag_at = |edge: HalfEdgeIndex, at_end: bool| -> FlagIndex {
+            let first = first_flag[edge.index as usize];
+            match first.is_unset() {
+                true => FlagIndex::unset(),
+                false => FlagIndex::new(first.index + at_end as u32)
+            }
+        };
+
+        let sigmas = flags.iter()
+            .enumerate()
+            .map(|(i, flag)| {
+                let at_end = i % 2 == 1;
+                let edge = mesh.half_edge(flag.half_edge);
+                let pair = HalfEdgeCollection::edge_pair_index(flag.half_edge);
+                match at_end {
+                    false => [
+                        flag_at(flag.half_edge, true),
+                        flag_at(edge.previous_edge, true),
+                        flag_at(pair, true)],
+                    true => [
+                        flag_at(flag.half_edge, false),
+                        flag_at(edge.next_edge, false),
+                        flag_at(pair, false)]
+                }
+            })
+            .collect();
+
+        FlagGraph { flags, sigmas }
+    }
+
+    pub fn len(&self) -> usize {
+        self.flags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty()
+    }
+
+    pub fn flag(&self, index: FlagIndex) -> &Flag {
+        &self.flags[index.index as usize]
+    }
+
+    /// The flag differing only in its vertex
+    pub fn sigma0(&self, index: FlagIndex) -> FlagIndex {
+        self.sigmas[index.index as usize][0]
+    }
+
+    /// The flag differing only in its edge
+    pub fn sigma1(&self, index: FlagIndex) -> FlagIndex {
+        self.sigmas[index.index as usize][1]
+    }
+
+    /// The flag differing only in its face, unset on a boundary edge
+    pub fn sigma2(&self, index: FlagIndex) -> FlagIndex {
+        self.sigmas[index.index as usize][2]
+    }
+}
+
+impl Mesh {
+    pub fn flag_graph(&self) -> FlagGraph {
+        FlagGraph::new(self)
+    }
+}
@@ -0,0 +1,140 @@
+use super::{Mesh, Point, VertexIndex, FaceIndex, HalfEdgeIndex, HalfEdgeCollection, SeedScale, MeshError};
+use std::collections::{HashMap, HashSet};
+
+/// Triangular seed whose faces are subdivided into a geodesic sphere
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GeodesicSeed {
+    Tetrahedron,
+    Octahedron,
+    Icosahedron,
+}
+
+// a point of the triangular lattice, in the basis of two unit vectors 60° apart
+type Lattice = (i64, i64);
+
+// Goldberg-Coxeter subdivision. Every seed face is laid over the triangular
+// lattice with its corners at 0, (m, n) and (m, n) rotated by 60°. The small
+// lattice triangles with their centroid on the face become the new faces, and
+// the lattice points beyond an edge are placed on the face across that edge.
+impl Mesh {
+    /// Geodesic polyhedron with frequency (m, n) on the unit sphere. (m, 0) is
+    /// class I, (m, m) class II and every other pair class III. Each seed face is
+    /// split into m² + mn + n² triangles. Fails if both m and n are zero.
+    pub fn geodesic(seed: GeodesicSeed, m: usize, n: usize) -> Result<Mesh, MeshError> {
+        if m + n == 0 {
+            return Err(MeshError::ZeroFrequency);
+        }
+
+        let seed = match seed {
+            GeodesicSeed::Tetrahedron => Mesh::tetrahedron(SeedScale::UnitCircumradius),
+            GeodesicSeed::Octahedron => Mesh::octahedron(SeedScale::UnitCircumradius),
+            GeodesicSeed::Icosahedron => Mesh::icosahedron(SeedScale::UnitCircumradius),
+        };
+        let (m, n) = (m as i64, n as i64);
+        let corners: [Lattice; 3] = [(0, 0), (m, n), (-n, m + n)];
+        let scaled: [Lattice; 3] = [(0, 0), (3 * m, 3 * n), (-3 * n, 3 * (m + n))];
+
+        let mut builder = Subdivision { seed: &seed, mesh: Mesh::new(), vertices: HashMap::new() };
+        let mut triangles: Vec<[VertexIndex; 3]> = Vec::new();
+        for f in 0..seed.face_count() {
+            let face = FaceIndex::new(f as u32);
+            let edges: Vec<HalfEdgeIndex> = seed.face_circulator(face)?.collect::<Result<_, _>>()?;
+
+            for a in (-n - 1)..=(m + 1) {
+                for b in -1..=(m + n + 1) {
+                    let up = [(a, b), (a + 1, b), (a, b + 1)];
+                    let down = [(a + 1, b), (a + 1, b + 1), (a, b + 1)];
+                    for triangle in [up, down].iter() {
+                        // compare three times the centroid to stay in integers
+                        let centroid = (triangle.iter().map(|p| p.0).sum(), triangle.iter().map(|p| p.1).sum());
+                        if barycentric(&scaled, centroid).iter().any(|w| *w < 0) {
+                            continue;
+                        }
+
+                        let mut indices = [VertexIndex::new(0); 3];
+                        for (index, point) in indices.iter_mut().zip(triangle.iter()) {
+                            *index = builder.vertex(&edges, &corners, *point);
+                        }
+                        triangles.push(indices);
+                    }
+                }
+            }
+        }
+
+        // triangles cut in half by a class II edge are found from both faces
+        let mut seen: HashSet<[u32; 3]> = HashSet::new();
+        let mut mesh = builder.mesh;
+        for triangle in triangles {
+            let mut key = [triangle[0].index, triangle[1].index, triangle[2].index];
+            key.sort_unstable();
+            if !seen.insert(key) {
+                continue;
+            }
+            mesh.add_face_by_indices(triangle.to_vec())?;
+        }
+
+        mesh.project_to_sphere(1.0, Point::new());
+        Ok(mesh)
+    }
+
+    /// Goldberg polyhedron GP(m, n), the reciprocal of the icosahedral geodesic
+    /// polyhedron about the unit sphere: 12 pentagons and 10(m² + mn + n² - 1) hexagons.
+    /// Fails if both m and n are zero.
+    pub fn goldberg(m: usize, n: usize) -> Result<Mesh, MeshError> {
+        Mesh::geodesic(GeodesicSeed::Icosahedron, m, n)?.reciprocal(1.0, Point::new())
+    }
+}
+
+struct Subdivision<'a> {
+    seed: &'a Mesh,
+    mesh: Mesh,
+    // lattice points keyed by their seed vertices and integer barycentric weights,
+    // which is the same from both faces of an edge
+    vertices: HashMap<Vec<(u32, i64)>, VertexIndex>,
+}
+
+impl<'a> Subdivision<'a> {
+    fn vertex(&mut self, edges: &[HalfEdgeIndex], corners: &[Lattice; 3], point: Lattice) -> VertexIndex {
+        let mut face: Vec<VertexIndex> = edges.iter().map(|edge| self.seed.half_edge(*edge).start_vertex).collect();
+        let mut corners = *corners;
+        let mut weights = barycentric(&corners, point);
+
+        // unfold the face across the edge opposite a negative weight
+        if let Some(k) = weights.iter().position(|w| *w < 0) {
+            let edge = HalfEdgeCollection::edge_pair_index(edges[(k + 1) % 3]);
+            let opposite = self.seed.half_edge(self.seed.half_edge(edge).previous_edge).start_vertex;
+            let (a, b) = (corners[(k + 1) % 3], corners[(k + 2) % 3]);
+            corners[k] = (a.0 + b.0 - corners[k].0, a.1 + b.1 - corners[k].1);
+            face[k] = opposite;
+            weights = barycentric(&corners, point);
+        }
+
+        let total: i64 = weights.iter().sum();
+        let mut key: Vec<(u32, i64)> = face.iter()
+            .zip(weights.iter())
+            .filter(|(_, w)| **w != 0)
+            .map(|(v, w)| (v.index, *w))
+            .collect();
+        key.sort_unstable();
+
+        let seed = self.seed;
+        let mesh = &mut self.mesh;
+        *self.vertices.entry(key).or_insert_with(|| {
+            let position = face.iter()
+                .zip(weights.iter())
+                .fold(Point::new(), |sum, (v, w)| sum + seed.vertex(*v).location * (*w as f64 / total as f64));
+            mesh.add_vertex_position(position)
+        })
+    }
+}
+
+// barycentric weights of the point, scaled by twice the signed triangle area
+fn barycentric(corners: &[Lattice; 3], point: Lattice) -> [i64; 3] {
+    let cross = |a: Lattice, b: Lattice| (a.0 - point.0) * (b.1 - point.1) - (a.1 - point.1) * (b.0 - point.0);
+    let sign = if cross(corners[1], corners[2]) + cross(corners[2], corners[0]) + cross(corners[0], corners[1]) < 0 { -1 } else { 1 };
+    [
+        sign * cross(corners[1], corners[2]),
+        sign * cross(corners[2], corners[0]),
+        sign * cross(corners[0], corners[1])
+    ]
+}
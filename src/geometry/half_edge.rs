@@ -1,5 +1,5 @@
 use super::constants::{UNSET_VALUE};
-use super::{VertexIndex, FaceIndex, MeshPartCollection, UnsetValue};
+use super::{VertexIndex, FaceIndex, MeshPartCollection, UnsetValue, MeshError};
 use std::ops::{Index, IndexMut};
 
 #[derive(PartialEq, Copy, Clone, PartialOrd, Debug)]
@@ -19,7 +19,7 @@ impl UnsetValue for HalfEdgeIndex {
 
 impl HalfEdgeIndex {
     pub fn new(index: u32) -> HalfEdgeIndex {
-        HalfEdgeIndex {index: index}
+        HalfEdgeIndex {index}
     }
 
     pub fn increment(&mut self) {
@@ -121,79 +121,32 @@ impl HalfEdgeCollection {
 
     pub fn end_vertex_index(&self, index: HalfEdgeIndex) -> Option<VertexIndex> {
         let pair = self.edge_pair(index);
-        match pair {
-            None => None,
-            Some(edge) => Option::Some(edge.start_vertex)
-        }
+        pair.map(|edge| edge.start_vertex)
     }
 
-    pub fn vertex_circulator(&self, index: HalfEdgeIndex) -> Option<Vec<HalfEdgeIndex>> {
+    /// Lazily walks the outgoing half-edges around the start vertex of `index`
+    pub fn vertex_circulator(&self, index: HalfEdgeIndex) -> Result<VertexCirculator<'_>, MeshError> {
         if (index.is_unset()) || (index.index >= self.len() as u32) {
-            return Option::None;
+            return Err(MeshError::IndexOutOfRange(index.index));
         }
 
-        let mut early_exit = false;
-        let mut cur_edge_index = index;
-        let mut edges: Vec<HalfEdgeIndex> = vec![cur_edge_index];
-        for _i in 0..100 {
-            cur_edge_index = self[HalfEdgeCollection::edge_pair_index(cur_edge_index)].next_edge;
-            println!("cur_edge_index in v_circulator: {:?}", cur_edge_index);
-            if cur_edge_index == index {
-                early_exit = true;
-                break;
-            }
-            if cur_edge_index.is_unset() {
-                panic!("Edge index is unset! Cant continue on circulator!");
-            }
-
-            edges.push(cur_edge_index);
-        }
-
-        if !early_exit {
-            panic!("Vertex circulator ran out of iterations!");
-        }
-
-        println!("v_circulator returning edges: {:#?}", edges);
-        Option::Some(edges)
+        Ok(VertexCirculator { edges: self, first: index, current: Some(index), steps: 0 })
     }
 
-    pub fn face_circulator(&self, index: HalfEdgeIndex) -> Option<Vec<HalfEdgeIndex>> {
-        if index.index >= self.len() as u32 {
-            return Option::None;
-        }
-        println!("calculating face_circulator for: {:?}", index);
-        let mut early_exit = false;
-        let mut cur_edge_index = index;
-        let mut edges: Vec<HalfEdgeIndex> = vec![cur_edge_index];
-        for i in 0..100 {
-            cur_edge_index = self[cur_edge_index].next_edge;
-            println!("cur_next_index is: {:?}", cur_edge_index);
-            if cur_edge_index == index {
-                early_exit = true;
-                break;
-            }
-            if cur_edge_index.is_unset() {
-                panic!("Edge index is unset! Cant continue on circulator!");
-            }
-
-            edges.push(cur_edge_index);
-        }
-
-        if !early_exit {
-            panic!("Vertex circulator ran out of iterations!");
+    /// Lazily walks the half-edges around the face adjacent to `index`, starting with `index`
+    pub fn face_circulator(&self, index: HalfEdgeIndex) -> Result<FaceCirculator<'_>, MeshError> {
+        if (index.is_unset()) || (index.index >= self.len() as u32) {
+            return Err(MeshError::IndexOutOfRange(index.index));
         }
 
-        Option::Some(edges)
+        trace!("calculating face_circulator for: {:?}", index);
+        Ok(FaceCirculator { edges: self, first: index, current: Some(index), steps: 0 })
     }
 
     pub fn is_boundary_index(&self, index: HalfEdgeIndex) -> Option<bool> {
-        println!("Edge pair for {:#?} is {:#?}", index, self.edge_pair(index));
-        match self.edge_pair(index) {
-            None => None,
-            Some(pair) => {
-                Option::Some((self[index].adjacent_face.is_unset()) || pair.adjacent_face.is_unset())
-            }
-        }
+        trace!("Edge pair for {:?} is {:?}", index, self.edge_pair(index));
+        self.edge_pair(index)
+            .map(|pair| (self[index].adjacent_face.is_unset()) || pair.adjacent_face.is_unset())
     }
 
     pub fn make_consecutive(&mut self, prev: HalfEdgeIndex, next: HalfEdgeIndex) {
@@ -209,3 +162,58 @@ impl HalfEdgeCollection {
         self.edges.drain(start.index as usize..start.index as usize + count);
     }
 }
+
+// Shared step of both circulators. A closed loop visits every half-edge at most
+// once, so a walk longer than the half-edge count can never return to its start.
+fn circulate(edges: &HalfEdgeCollection, first: HalfEdgeIndex, current: &mut Option<HalfEdgeIndex>, steps: &mut usize, step: fn(&HalfEdgeCollection, HalfEdgeIndex) -> HalfEdgeIndex) -> Option<Result<HalfEdgeIndex, MeshError>> {
+    let index = (*current)?;
+    if index.is_unset() || index.index >= edges.len() as u32 || *steps >= edges.len() {
+        *current = None;
+        return Some(Err(MeshError::BrokenLoop(first)));
+    }
+
+    *steps += 1;
+    let following = step(edges, index);
+    *current = if following == first { None } else { Some(following) };
+    Some(Ok(index))
+}
+
+/// Outgoing half-edges around a vertex, yields `BrokenLoop` once if the
+/// links never lead back to the first half-edge
+pub struct VertexCirculator<'a> {
+    edges: &'a HalfEdgeCollection,
+    first: HalfEdgeIndex,
+    current: Option<HalfEdgeIndex>,
+    steps: usize,
+}
+
+impl<'a> Iterator for VertexCirculator<'a> {
+    type Item = Result<HalfEdgeIndex, MeshError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = circulate(self.edges, self.first, &mut self.current, &mut self.steps,
+            |edges, index| edges[HalfEdgeCollection::edge_pair_index(index)].next_edge);
+        trace!("cur_edge_index in v_circulator: {:?}", item);
+        item
+    }
+}
+
+/// Half-edges around a face, yields `BrokenLoop` once if the
+/// links never lead back to the first half-edge
+pub struct FaceCirculator<'a> {
+    edges: &'a HalfEdgeCollection,
+    first: HalfEdgeIndex,
+    current: Option<HalfEdgeIndex>,
+    steps: usize,
+}
+
+impl<'a> Iterator for FaceCirculator<'a> {
+    type Item = Result<HalfEdgeIndex, MeshError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = circulate(self.edges, self.first, &mut self.current, &mut self.steps,
+            |edges, index| edges[index].next_edge);
+        trace!("cur_next_index is: {:?}", item);
+        item
+    }
+}
@@ -0,0 +1,100 @@
+use super::{Mesh, Point, FaceIndex, MeshError};
+
+// Whole-mesh measurements for closed meshes. The volume integrals sum the signed
+// tetrahedra spanned by the origin and a triangle fan of every face, so the
+// result is independent of the origin as long as the mesh is closed.
+impl Mesh {
+    /// Enclosed volume, positive when the faces are wound counter-clockwise seen from outside
+    pub fn volume(&self) -> Result<f64, MeshError> {
+        self.check_closed()?;
+        Ok(self.face_triangles().iter()
+            .map(|(a, b, c)| a.dot(b.cross(*c)) / 6.0)
+            .sum())
+    }
+
+    pub fn surface_area(&self) -> Result<f64, MeshError> {
+        self.check_closed()?;
+        Ok((0..self.face_count())
+            .map(|i| self.face_area(FaceIndex::new(i as u32)))
+            .sum())
+    }
+
+    /// Center of mass of the enclosed solid with uniform density
+    pub fn volume_centroid(&self) -> Result<Point, MeshError> {
+        self.check_closed()?;
+        let (volume, moment) = self.face_triangles().iter()
+            .fold((0.0, Point::new()), |(volume, moment), (a, b, c)| {
+                let tetrahedron = a.dot(b.cross(*c)) / 6.0;
+                (volume + tetrahedron, moment + (*a + *b + *c) * (tetrahedron / 4.0))
+            });
+
+        if volume.abs() < f64::EPSILON {
+            return Err(MeshError::ZeroVolume);
+        }
+        Ok(moment / volume)
+    }
+
+    /// Inertia tensor of the enclosed solid with unit density, about its volume centroid
+    pub fn inertia_tensor(&self) -> Result<[[f64; 3]; 3], MeshError> {
+        let center = self.volume_centroid()?;
+
+        // second moments ∫ x_i x_j dV about the centroid
+        let mut covariance = [[0.0; 3]; 3];
+        let mut volume = 0.0;
+        for (a, b, c) in self.face_triangles() {
+            let (a, b, c) = (a - center, b - center, c - center);
+            let tetrahedron = a.dot(b.cross(c)) / 6.0;
+            let corners = [components(a), components(b), components(c)];
+            let sum = components(a + b + c);
+            for i in 0..3 {
+                for j in 0..3 {
+                    let products: f64 = corners.iter().map(|p| p[i] * p[j]).sum();
+                    covariance[i][j] += tetrahedron / 20.0 * (products + sum[i] * sum[j]);
+                }
+            }
+            volume += tetrahedron;
+        }
+
+        if volume.abs() < f64::EPSILON {
+            return Err(MeshError::ZeroVolume);
+        }
+
+        let trace = covariance[0][0] + covariance[1][1] + covariance[2][2];
+        let mut tensor = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                let diagonal = if i == j { trace } else { 0.0 };
+                tensor[i][j] = diagonal - covariance[i][j];
+            }
+        }
+
+        Ok(tensor)
+    }
+
+    fn check_closed(&self) -> Result<(), MeshError> {
+        match self.find_boundary_half_edge() {
+            Some(index) => Err(MeshError::BoundaryEdge(index)),
+            None => Ok(())
+        }
+    }
+
+    // fan triangulation of every face around its first vertex
+    fn face_triangles(&self) -> Vec<(Point, Point, Point)> {
+        let mut triangles = Vec::new();
+        for i in 0..self.face_count() {
+            let points: Vec<Point> = match self.face_vertices(FaceIndex::new(i as u32)) {
+                Err(_) => continue,
+                Ok(vertices) => vertices.map(|vertex| self.vertex(vertex).location).collect()
+            };
+            for j in 1..points.len() - 1 {
+                triangles.push((points[0], points[j], points[j + 1]));
+            }
+        }
+
+        triangles
+    }
+}
+
+fn components(p: Point) -> [f64; 3] {
+    [p.x, p.y, p.z]
+}
@@ -0,0 +1,153 @@
+use super::{Mesh, Point, Vector, FaceIndex, VertexIndex, UnsetValue, MeshError};
+
+/// How the normals of the faces around a vertex contribute to its normal
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Weighting {
+    Uniform,
+    Area,
+    Angle
+}
+
+// Face and vertex measurements, walking the half-edges around faces and vertices
+impl Mesh {
+    /// Unit normal of the face by Newell's method, which stays well defined for
+    /// non-planar and non-convex faces. Degenerate faces return the zero vector.
+    pub fn face_normal(&self, index: FaceIndex) -> Vector {
+        newell_vector(&self.face_locations(index)).normalize()
+    }
+
+    /// Area of the face, exact for planar faces and the area of the
+    /// projection onto the Newell plane otherwise
+    pub fn face_area(&self, index: FaceIndex) -> f64 {
+        newell_vector(&self.face_locations(index)).length() / 2.0
+    }
+
+    /// Largest distance of a face vertex from the least-squares plane through
+    /// its vertices. Zero for planar faces.
+    pub fn face_planarity_error(&self, index: FaceIndex) -> f64 {
+        let points = self.face_locations(index);
+        if points.is_empty() {
+            return 0.0;
+        }
+
+        let center = points.iter().fold(Point::new(), |sum, p| sum + *p) / points.len() as f64;
+        let normal = best_fit_normal(&points, center);
+        points.iter()
+            .map(|p| (*p - center).dot(normal).abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// Unit normal at the vertex, the weighted sum of the adjacent face normals.
+    /// Half-edges on a boundary without a face are skipped.
+    pub fn vertex_normal(&self, index: VertexIndex, weighting: Weighting) -> Vector {
+        let mut outgoing = match self.vertex_circulator(index) {
+            Err(_) => return Point::new(),
+            Ok(circulator) => circulator
+        };
+
+        let location = self.vertex(index).location;
+        let sum: Result<Point, MeshError> = outgoing.try_fold(Point::new(), |sum, edge| {
+            let edge = edge?;
+            let half_edge = self.half_edge(edge);
+            let face = half_edge.adjacent_face;
+            if face.is_unset() {
+                return Ok(sum);
+            }
+
+            let weight = match weighting {
+                Weighting::Uniform => 1.0,
+                Weighting::Area => self.face_area(face),
+                Weighting::Angle => {
+                    let next = self.vertex(self.find_end_vertex_index(edge)).location - location;
+                    let previous = self.vertex(self.half_edge(half_edge.previous_edge).start_vertex).location - location;
+                    next.cross(previous).length().atan2(next.dot(previous))
+                }
+            };
+            Ok(sum + self.face_normal(face) * weight)
+        });
+
+        sum.map(|sum| sum.normalize()).unwrap_or_default()
+    }
+
+    fn face_locations(&self, index: FaceIndex) -> Vec<Point> {
+        self.face_circulator(index)
+            .and_then(|circulator| circulator
+                .map(|edge| edge.map(|edge| self.vertex(self.half_edge(edge).start_vertex).location))
+                .collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Sum of the cross products of consecutive polygon vertices, twice the vector area.
+/// Normal to the polygon plane and also defined for non-planar polygons.
+pub fn newell_vector(points: &[Point]) -> Vector {
+    let n = points.len();
+    (0..n).fold(Point::new(), |sum, i| sum + points[i].cross(points[(i + 1) % n]))
+}
+
+// normal of the least-squares plane through `center`, the eigenvector of the
+// scatter matrix with the smallest eigenvalue, found by cyclic Jacobi rotations
+fn best_fit_normal(points: &[Point], center: Point) -> Vector {
+    let mut scatter = [[0.0; 3]; 3];
+    for point in points {
+        let offset = *point - center;
+        let d = [offset.x, offset.y, offset.z];
+        for (row, di) in scatter.iter_mut().zip(d.iter()) {
+            for (entry, dj) in row.iter_mut().zip(d.iter()) {
+                *entry += di * dj;
+            }
+        }
+    }
+
+    let mut vectors = IDENTITY;
+    for _sweep in 0..JACOBI_SWEEPS {
+        for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+            if scatter[p][q] == 0.0 {
+                continue;
+            }
+
+            // rotation in the (p, q) plane that zeroes the off-diagonal entry
+            let theta = (scatter[q][q] - scatter[p][p]) / (2.0 * scatter[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let mut rotation = IDENTITY;
+            rotation[p][p] = c;
+            rotation[q][q] = c;
+            rotation[p][q] = t * c;
+            rotation[q][p] = -t * c;
+
+            scatter = multiply(&transpose(&rotation), &multiply(&scatter, &rotation));
+            vectors = multiply(&vectors, &rotation);
+        }
+    }
+
+    let smallest = (0..3)
+        .min_by(|a, b| scatter[*a][*a].total_cmp(&scatter[*b][*b]))
+        .unwrap_or(2);
+    Point::from_values(vectors[0][smallest], vectors[1][smallest], vectors[2][smallest])
+}
+
+// three sweeps already bring a 3x3 matrix to machine precision
+const JACOBI_SWEEPS: usize = 8;
+
+const IDENTITY: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+fn multiply(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            *entry = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    result
+}
+
+fn transpose(a: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, entry) in row.iter_mut().enumerate() {
+            *entry = a[j][i];
+        }
+    }
+    result
+}
@@ -1,5 +1,4 @@
 use super::{*};
-use super::constants::{UNSET_VALUE};
 use super::{MeshPartCollection, UnsetValue};
 use std::fmt;
 
@@ -15,6 +14,12 @@ impl fmt::Debug for Mesh {
     }
 }
 
+impl Default for Mesh {
+    fn default() -> Self {
+        Mesh::new()
+    }
+}
+
 // general methods
 impl Mesh {
     pub fn new() -> Mesh {
@@ -25,10 +30,13 @@ impl Mesh {
         }
     }
 
-    pub fn compact(&mut self) {
+    /// Drops unused vertices, faces and half-edges. Fails before anything
+    /// is moved if only one half of a half-edge pair is in use.
+    pub fn compact(&mut self) -> Result<(), MeshError> {
+        self.half_edge_compact()?;
         self.vertex_compact();
         self.face_compact();
-        self.half_edge_compact();
+        Ok(())
     }
 }
 
@@ -38,6 +46,10 @@ impl Mesh {
         self.vertices.len()
     }
 
+    pub fn vertex(&self, index: VertexIndex) -> &Vertex {
+        &self.vertices[index]
+    }
+
     pub fn add_vertex(&mut self, v: Vertex) -> VertexIndex {
         self.vertices.add(v)
     }
@@ -46,27 +58,44 @@ impl Mesh {
         self.vertices.add(Vertex::new(position))
     }
 
-    pub fn get_vertex_circulator(&self, index: VertexIndex) -> Option<Vec<HalfEdgeIndex>> {
+    pub fn set_vertex_position(&mut self, index: VertexIndex, position: Point) {
+        self.vertices[index].location = position;
+    }
+
+    /// Lazily walks the outgoing half-edges of the vertex
+    pub fn vertex_circulator(&self, index: VertexIndex) -> Result<VertexCirculator<'_>, MeshError> {
+        if index.index >= self.vertex_count() as u32 {
+            return Err(MeshError::IndexOutOfRange(index.index));
+        }
+        if self.vertices[index].outgoing_half_edge.is_unset() {
+            return Err(MeshError::IsolatedVertex(index));
+        }
         self.edges.vertex_circulator(self.vertices[index].outgoing_half_edge)
     }
 
     fn vertex_compact(&mut self) {
         let mut marker = VertexIndex::new(0);
+        let mut moved_to = vec![VertexIndex::unset(); self.vertex_count()];
 
-        for i in 0..self.vertex_count() {
+        for (i, target) in moved_to.iter_mut().enumerate() {
             let iter = VertexIndex::new(i as u32);
 
             if !self.vertices[iter].is_unused() {
                 if marker < iter {
                     self.vertices[marker] = self.vertices[iter];
-
-                    // update all outgoing halfedges
-                    let first = self.vertices[marker].outgoing_half_edge;
-                    for edge_index in self.edges.vertex_circulator(first).unwrap() {
-                        self.edges[edge_index].start_vertex = marker;
-                    }
-                marker.increment();
                 }
+                *target = marker;
+                marker.increment();
+            }
+        }
+
+        // update the start vertex of every half-edge in one pass,
+        // this does not depend on intact loops around the vertices
+        for i in 0..self.half_edge_count() {
+            let edge_index = HalfEdgeIndex::new(i as u32);
+            let start = self.edges[edge_index].start_vertex;
+            if !start.is_unset() {
+                self.edges[edge_index].start_vertex = moved_to[start.index as usize];
             }
         }
 
@@ -83,6 +112,10 @@ impl Mesh {
         self.edges.len()
     }
 
+    pub fn half_edge(&self, index: HalfEdgeIndex) -> &HalfEdge {
+        &self.edges[index]
+    }
+
     pub fn add_half_edge(&mut self, e: HalfEdge) -> HalfEdgeIndex {
         self.edges.add(e)
     }
@@ -104,25 +137,27 @@ impl Mesh {
         index1
     }
 
+    /// The first used half-edge lying on a boundary, None for closed meshes
+    pub fn find_boundary_half_edge(&self) -> Option<HalfEdgeIndex> {
+        (0..self.half_edge_count())
+            .map(|i| HalfEdgeIndex::new(i as u32))
+            .filter(|index| !self.edges[*index].is_unused())
+            .find(|index| self.edges.is_boundary_index(*index) == Some(true))
+    }
+
     pub fn find_end_vertex_index(&self, index: HalfEdgeIndex) -> VertexIndex {
         self.edges[HalfEdgeCollection::edge_pair_index(index)].start_vertex
     }
 
     pub fn find_half_edge_index(&self, start: VertexIndex, end: VertexIndex) -> Option<HalfEdgeIndex> {
         let halfedge_index = self.vertices[start].outgoing_half_edge;
-        println!("outgoing edge for {:?} is: {:?}", start, halfedge_index);
+        trace!("outgoing edge for {:?} is: {:?}", start, halfedge_index);
         let result = self.edges.vertex_circulator(halfedge_index);
         match result {
-            None => return Option::None,
-            Some(circulator) => {
-                for index in circulator {
-                    if end == self.find_end_vertex_index(index) {
-                        return Some(index);
-                    }
-                }
-                return Option::None
-            }
-        };
+            Err(_) => Option::None,
+            Ok(mut circulator) => circulator
+                .find_map(|index| index.ok().filter(|index| end == self.find_end_vertex_index(*index)))
+        }
     }
 
     pub fn remove_half_edge_pair(&mut self, index: HalfEdgeIndex) {
@@ -159,7 +194,18 @@ impl Mesh {
         self.edges[pair] = HalfEdge::unset();
     }
 
-    fn half_edge_compact(&mut self){
+    fn half_edge_compact(&mut self) -> Result<(), MeshError> {
+        // both halves of a pair have to be kept or dropped together
+        if !self.half_edge_count().is_multiple_of(2) {
+            return Err(MeshError::UnpairedHalfEdge(HalfEdgeIndex::new(self.half_edge_count() as u32 - 1)));
+        }
+        for i in (0..self.half_edge_count()).step_by(2) {
+            let index = HalfEdgeIndex::new(i as u32);
+            if self.edges[index].is_unused() != self.edges[HalfEdgeCollection::edge_pair_index(index)].is_unused() {
+                return Err(MeshError::UnpairedHalfEdge(index));
+            }
+        }
+
         let mut marker = HalfEdgeIndex::new(0);
 
         for i in 0..self.half_edge_count() {
@@ -167,7 +213,7 @@ impl Mesh {
             let iter = HalfEdgeIndex::new(i as u32);
 
             // check if used
-            if !self.edges[marker].is_unused() {
+            if !self.edges[iter].is_unused() {
                 if marker < iter {
                     // Copy current edge to marker slot
                     self.edges[marker] = self.edges[iter];
@@ -179,7 +225,7 @@ impl Mesh {
                     }
 
                     // update adjacent face if necessary
-                    if self.edges[marker].adjacent_face.is_unset() {
+                    if !self.edges[marker].adjacent_face.is_unset() {
                         let f_index = self.edges[marker].adjacent_face;
                         if self.faces[f_index].first_half_edge == iter {
                             self.faces[f_index].first_half_edge = marker;
@@ -196,15 +242,12 @@ impl Mesh {
             }
         }
 
-        // check if even count of edges
-        if marker.index % 2 != 0 {
-            panic!("Halfedge count was uneven after compact call!");
-        }
-
         // trim list
         if marker.index < self.half_edge_count() as u32 {
             self.edges.remove_range(marker, self.half_edge_count() - marker.index as usize)
         }
+
+        Ok(())
     }
 }
 
@@ -214,43 +257,47 @@ impl Mesh {
         self.faces.len()
     }
 
+    pub fn face(&self, index: FaceIndex) -> &Face {
+        &self.faces[index]
+    }
+
     pub fn add_face(&mut self, face: Face) -> FaceIndex {
         self.faces.add(face)
     }
 
-    pub fn add_face_by_indices(&mut self, indices:Vec<VertexIndex>) -> FaceIndex {
+    /// Adds a face through the given vertices, counter-clockwise seen from outside.
+    /// The mesh is left unchanged if the face can not be added.
+    pub fn add_face_by_indices(&mut self, indices:Vec<VertexIndex>) -> Result<FaceIndex, MeshError> {
         let n = indices.len();
-        let unset = FaceIndex::unset();
 
         // Check for degenerate
         if n < 3 {
-            println!("add_face_by_indices ERROR: Degenerate vertex count!");
-            return unset;
+            return Err(MeshError::DegenerateFace);
+        }
+        for i in 0..n {
+            if indices[i + 1..].contains(&indices[i]) {
+                return Err(MeshError::DegenerateFace);
+            }
         }
 
         // test if vertices are valid
         let v_count = self.vertex_count();
-        for index in indices.clone() {
+        for index in &indices {
             if index.index >= v_count as u32 {
-                panic!("Vertex index out of range!");
+                return Err(MeshError::IndexOutOfRange(index.index));
             }
-            let outgoing_halfedge_index = self.vertices[index].outgoing_half_edge;
-
-            match self.edges.is_boundary_index(outgoing_halfedge_index) {
-                None => (), // no half edge defined, fine for now
-                Some(is_boundary) => {
-                    if !is_boundary {
-                        println!("add_face_by_indices ERROR: topology problem at vertex {:?}: outgoing edge {:?} is not a boundary edge!", index, outgoing_halfedge_index);
-                        return unset;
-                    }
-                }
+            let outgoing_halfedge_index = self.vertices[*index].outgoing_half_edge;
+
+            // no half edge defined (None) is fine for now
+            if let Some(false) = self.edges.is_boundary_index(outgoing_halfedge_index) {
+                return Err(MeshError::NonManifoldVertex(*index));
             }
         }
 
         // test each vertex pair, if they already share an half-edge
         // if so, check if that pair is already linked to a face
         // else, create it
-        println!("add_face_by_indices STATUS: Testing vertex pairs for shared half-edges");
+        debug!("add_face_by_indices STATUS: Testing vertex pairs for shared half-edges");
         let mut edges = vec![HalfEdgeIndex::unset(); n];
         let mut is_new = vec![false; n];
         let face_index = FaceIndex::new(self.face_count() as u32);
@@ -261,21 +308,69 @@ impl Mesh {
             match self.find_half_edge_index(cur_index, next_index) {
                 None => {
                     is_new[i] = true;
-                    // edges[i] = self.add_edge_pair(cur_index, next_index, face_index);
-                    // println!("add_face_by_indices STATUS: added edge pair: {:#?}", edges[i])
                 }
                 Some(index) => {
-                    println!("add_face_by_indices STATUS: found an index: {:?}", index);
-                    if !index.is_unset() { // already an adjacent face -> non-manifold
-                        println!("add_face_by_indices ERROR: half-edge {:?} already had an adjacent face defined, defining another here would lead to non-manifald topology. Aborting!", index);
-                        return unset;
+                    trace!("add_face_by_indices STATUS: found an index: {:?}", index);
+                    if !self.edges[index].adjacent_face.is_unset() { // already an adjacent face -> non-manifold
+                        return Err(MeshError::NonManifoldEdge(index));
                     }
-                    // self.edges[index].adjacent_face = face_index;
                     edges[i] = index;
                 }
             }
         }
 
+        // re-link patches if necessary: two consecutive old half-edges have
+        // to follow each other directly in the boundary loop, otherwise the
+        // half-edges in between are moved to another free gap at the vertex.
+        // A re-link only reads and changes the links around its own vertex and
+        // every vertex is re-linked at most once, so all gaps are searched
+        // before the first link changes and a failure leaves the mesh untouched.
+        let mut relinks: Vec<[HalfEdgeIndex; 4]> = Vec::new();
+        for i in 0..n {
+            let ii = (i + 1) % n;
+            if is_new[i] || is_new[ii] {
+                continue;
+            }
+
+            let inner_prev = edges[i];
+            let inner_next = edges[ii];
+            if self.edges[inner_prev].next_edge == inner_next {
+                continue;
+            }
+
+            // search a free gap, it will be between boundary_prev and boundary_next
+            let outer_prev = HalfEdgeCollection::edge_pair_index(inner_next);
+            let mut boundary_prev = outer_prev;
+            let mut found_gap = false;
+            for _i in 0..self.half_edge_count() {
+                boundary_prev = HalfEdgeCollection::edge_pair_index(self.edges[boundary_prev].next_edge);
+                if self.edges[boundary_prev].adjacent_face.is_unset() && boundary_prev != inner_prev {
+                    found_gap = true;
+                    break;
+                }
+            }
+            let boundary_next = self.edges[boundary_prev].next_edge;
+
+            if !found_gap || boundary_next == inner_next {
+                return Err(MeshError::NonManifoldVertex(indices[ii]));
+            }
+            relinks.push([inner_prev, inner_next, boundary_prev, boundary_next]);
+        }
+
+        for [inner_prev, inner_next, boundary_prev, boundary_next] in relinks {
+            let patch_start = self.edges[inner_prev].next_edge;
+            let patch_end = self.edges[inner_next].previous_edge;
+
+            self.edges.make_consecutive(boundary_prev, patch_start);
+            self.edges.make_consecutive(patch_end, boundary_next);
+            self.edges.make_consecutive(inner_prev, inner_next);
+        }
+
+        // isolated vertices have to be known before new half-edges are added
+        let is_isolated: Vec<bool> = indices.iter()
+            .map(|index| self.vertices[*index].outgoing_half_edge.is_unset())
+            .collect();
+
         // now create any missing halfedge pairs
         for i in 0..n {
 
@@ -291,115 +386,130 @@ impl Mesh {
             }
         }
 
-        // Link half-edges
+        // Collect the half-edge links first, the outer links read from
+        // the old connectivity, so they can only be stored afterwards
+        let mut next_cache: Vec<(HalfEdgeIndex, HalfEdgeIndex)> = Vec::with_capacity(3 * n);
+        let mut needs_adjust = vec![false; n];
         for i in 0..n {
             let ii = (i + 1) % n;
             let v2 = indices[ii];
-            let mut id = 0;
-            if is_new[i] {id += 1;}
-            if is_new[ii] {id += 2;}
-
-            if (id == 3) && (self.vertices[v2].outgoing_half_edge.is_unset()) {id += 1} // id == 4
-
-            if id > 0 { // at least one of the halfedge pairs is new
-                // link outer edges
-                let mut outer_prev = HalfEdgeIndex::unset();
-                let mut outer_next = HalfEdgeIndex::unset();
-                match id {
-                    1 => { // first is new, second is old
-                        outer_prev = self.edges[edges[ii]].previous_edge;
-                        outer_next = HalfEdgeCollection::edge_pair_index(edges[i]);
-                    },
-                    2 => { // second is new, first is old
-                        outer_prev = HalfEdgeCollection::edge_pair_index(edges[ii]);
-                        outer_next = self.edges[edges[i]].next_edge;
-                    },
-                    3 => { // both are new
-                        outer_prev = HalfEdgeCollection::edge_pair_index(edges[ii]);
-                        outer_next = HalfEdgeCollection::edge_pair_index(edges[i]);
-                    }
-                    4 => { // both are new (non-manifold vertex)
-                        outer_prev = self.edges[self.vertices[v2].outgoing_half_edge].previous_edge;
-                        outer_next = HalfEdgeCollection::edge_pair_index(edges[i]);
-                        self.edges[outer_prev].next_edge = outer_next;
-                        self.edges[outer_next].previous_edge = outer_prev;
-
-                        outer_prev = HalfEdgeCollection::edge_pair_index(edges[ii]);
-                        outer_next = self.vertices[v2].outgoing_half_edge;
-                    },
-                    _ => return unset
-                };
-
-                // outer_prev/next should now be set, so store links
-                if (!outer_next.is_unset()) && (!outer_prev.is_unset()) {
-                    self.edges[outer_prev].next_edge = outer_next;
-                    self.edges[outer_next].previous_edge = outer_prev;
-                }
+            let inner_prev = edges[i];
+            let inner_next = edges[ii];
 
-                // link inner halfedges
-                self.edges[edges[i]].next_edge = edges[ii];
-                self.edges[edges[ii]].previous_edge = edges[i];
+            if !is_new[i] && !is_new[ii] { // both old, patch is already linked
+                needs_adjust[ii] = self.vertices[v2].outgoing_half_edge == inner_next;
+                continue;
+            }
 
-                // ensure vertex->outgoing is boundary if vertex is boundary
-                if is_new[i] {
-                    let mut outgoing = edges[i];
-                    outgoing.increment();
-                    self.vertices[v2].outgoing_half_edge = outgoing;
-                }
+            let outer_prev = HalfEdgeCollection::edge_pair_index(inner_next);
+            let outer_next = HalfEdgeCollection::edge_pair_index(inner_prev);
+
+            // link outer edges
+            if is_new[i] && !is_new[ii] { // first is new, second is old
+                let boundary_prev = self.edges[inner_next].previous_edge;
+                next_cache.push((boundary_prev, outer_next));
+                self.vertices[v2].outgoing_half_edge = outer_next;
+            }
+            else if !is_new[i] && is_new[ii] { // second is new, first is old
+                let boundary_next = self.edges[inner_prev].next_edge;
+                next_cache.push((outer_prev, boundary_next));
+                self.vertices[v2].outgoing_half_edge = boundary_next;
+            }
+            else if is_isolated[ii] { // both are new
+                self.vertices[v2].outgoing_half_edge = outer_next;
+                next_cache.push((outer_prev, outer_next));
+            }
+            else { // both are new (non-manifold vertex)
+                let boundary_next = self.vertices[v2].outgoing_half_edge;
+                let boundary_prev = self.edges[boundary_next].previous_edge;
+                next_cache.push((boundary_prev, outer_next));
+                next_cache.push((outer_prev, boundary_next));
             }
 
-            else { // both old
-                unimplemented!();
+            // link inner halfedges
+            next_cache.push((inner_prev, inner_next));
+        }
+
+        for (prev, next) in next_cache {
+            self.edges.make_consecutive(prev, next);
+        }
+
+        // ensure vertex->outgoing is boundary if vertex is boundary
+        for i in 0..n {
+            if needs_adjust[i] {
+                self.adjust_outgoing_half_edge(indices[i]);
             }
         }
 
         // Add face
-        return self.add_face(Face::new(edges[0]));
+        Ok(self.add_face(Face::new(edges[0])))
     }
 
-    pub fn remove_face(&mut self, index: FaceIndex) {
-        match self.face_half_edge_indices(index) {
-            None => (),
-            Some(indices) => {
-                println!("edges in face are: {:#?}", indices);
-                for edge_index in indices {
-                    if self.edges.is_boundary_index(edge_index).unwrap() {
-                        self.remove_half_edge_pair(edge_index);
-                    }
-                    else {
-                        self.edges[edge_index].adjacent_face = FaceIndex::unset();
-                        self.vertices[self.edges[edge_index].start_vertex].outgoing_half_edge = edge_index;
-                    }
-                }
-                self.faces[index] = Face::unset();
+    fn adjust_outgoing_half_edge(&mut self, index: VertexIndex) {
+        let boundary = match self.vertex_circulator(index) {
+            Err(_) => None,
+            Ok(mut circulator) => circulator
+                .find_map(|edge| edge.ok().filter(|edge| self.edges[*edge].adjacent_face.is_unset()))
+        };
+        if let Some(edge_index) = boundary {
+            self.vertices[index].outgoing_half_edge = edge_index;
+        }
+    }
+
+    /// Removes the face together with the half-edge pairs it leaves without any face.
+    /// The half-edges are collected first since removing them changes the links.
+    pub fn remove_face(&mut self, index: FaceIndex) -> Result<(), MeshError> {
+        let indices: Vec<HalfEdgeIndex> = self.face_circulator(index)?.collect::<Result<_, _>>()?;
+        debug!("edges in face are: {:?}", indices);
+        for edge_index in indices {
+            // the circulator only yields half-edges in range, so the pair exists
+            if self.edges.is_boundary_index(edge_index) == Some(true) {
+                self.remove_half_edge_pair(edge_index);
+            }
+            else {
+                self.edges[edge_index].adjacent_face = FaceIndex::unset();
+                self.vertices[self.edges[edge_index].start_vertex].outgoing_half_edge = edge_index;
             }
         }
+        self.faces[index] = Face::unset();
+
+        Ok(())
     }
 
-    pub fn face_half_edge_indices(&self, index: FaceIndex) -> Option<Vec<HalfEdgeIndex>> {
+    /// Lazily walks the half-edges of the face
+    pub fn face_circulator(&self, index: FaceIndex) -> Result<FaceCirculator<'_>, MeshError> {
+        if index.index >= self.face_count() as u32 || self.faces[index].is_unused() {
+            return Err(MeshError::IndexOutOfRange(index.index));
+        }
         self.edges.face_circulator(self.faces[index].first_half_edge)
     }
 
     pub fn face_compact(&mut self) {
         let mut marker = FaceIndex::new(0);
+        let mut moved_to = vec![FaceIndex::unset(); self.face_count()];
 
-        for i in 0..self.face_count() {
+        for (i, target) in moved_to.iter_mut().enumerate() {
             let iter = FaceIndex::new(i as u32);
 
             // test valid face
             if !self.faces[iter].is_unused() {
                 if marker < iter {
                     self.faces[marker] = self.faces[iter];
-
-                    // update all adjacent half-edges
-                    for edge_index in self.face_half_edge_indices(marker).unwrap() {
-                        self.edges[edge_index].adjacent_face = marker;
-                    }
                 }
+                *target = marker;
                 marker.increment();
             }
         }
 
+        // update the adjacent face of every half-edge in one pass
+        for i in 0..self.half_edge_count() {
+            let edge_index = HalfEdgeIndex::new(i as u32);
+            let face = self.edges[edge_index].adjacent_face;
+            if !face.is_unset() {
+                self.edges[edge_index].adjacent_face = moved_to[face.index as usize];
+            }
+        }
+
         // trim list down to new size
         if marker.index < self.face_count() as u32 {
             self.faces.remove_range(marker, self.face_count() - marker.index as usize)
@@ -1,7 +1,7 @@
-pub use self::point::Point;
+pub use self::point::{Point, Vector};
 mod point;
 
-pub use self::half_edge::{HalfEdge, HalfEdgeIndex, HalfEdgeCollection};
+pub use self::half_edge::{HalfEdge, HalfEdgeIndex, HalfEdgeCollection, VertexCirculator, FaceCirculator};
 mod half_edge;
 
 pub use self::vertex::{Vertex, VertexIndex, VertexCollection};
@@ -10,13 +10,39 @@ mod vertex;
 pub use self::face::{Face, FaceIndex, FaceCollection};
 mod face;
 
+pub use self::edge::EdgeIndex;
+mod edge;
+
 pub use self::mesh::Mesh;
 mod mesh;
 
+pub use self::measure::{Weighting, newell_vector};
+mod measure;
+mod mass;
+
+pub use self::canonical::CanonicalReport;
+mod canonical;
+pub use self::relax::RelaxReport;
+mod relax;
+mod topology;
+mod sphere;
+
+pub use self::geodesic::GeodesicSeed;
+mod geodesic;
+
+pub use self::error::MeshError;
+mod error;
+
+pub use self::flag::{Flag, FlagIndex, FlagGraph};
+mod flag;
+
+pub use self::seeds::SeedScale;
+mod seeds;
+
 pub use self::traits::{MeshPartCollection, UnsetValue};
 mod traits;
 
 pub mod constants {
     // is 4294967295
-    pub const UNSET_VALUE: u32 = std::u32::MAX;
+    pub const UNSET_VALUE: u32 = u32::MAX;
 }
\ No newline at end of file
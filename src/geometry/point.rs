@@ -1,4 +1,5 @@
 use std::f64;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Point{
@@ -7,6 +8,15 @@ pub struct Point{
     pub z: f64,
 }
 
+/// Directions and offsets share the representation of positions
+pub type Vector = Point;
+
+impl Default for Point {
+    fn default() -> Self {
+        Point::new()
+    }
+}
+
 impl Point {
     pub fn new() -> Point {
         Point{
@@ -25,10 +35,98 @@ impl Point {
     }
 
     pub fn from_values(x: f64, y: f64, z: f64) -> Point {
-        Point {
-            x: x,
-            y: y,
-            z: z
+        Point { x, y, z }
+    }
+
+    pub fn dot(&self, other: Vector) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: Vector) -> Vector {
+        Point::from_values(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.dot(*self).sqrt()
+    }
+
+    /// Unit vector in the same direction, the zero vector stays zero
+    pub fn normalize(&self) -> Vector {
+        let length = self.length();
+        match length > 0.0 {
+            true => *self / length,
+            false => *self
         }
     }
-}
\ No newline at end of file
+
+    pub fn distance(&self, other: Point) -> f64 {
+        (other - *self).length()
+    }
+
+    /// Linear interpolation, t = 0 gives self and t = 1 gives other
+    pub fn lerp(&self, other: Point, t: f64) -> Point {
+        *self + (other - *self) * t
+    }
+
+    /// Component-wise comparison within an absolute tolerance
+    pub fn approx_eq(&self, other: Point, tolerance: f64) -> bool {
+        (self.x - other.x).abs() <= tolerance &&
+        (self.y - other.y).abs() <= tolerance &&
+        (self.z - other.z).abs() <= tolerance
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::from_values(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::from_values(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<f64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: f64) -> Point {
+        Point::from_values(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl Div<f64> for Point {
+    type Output = Point;
+
+    fn div(self, divisor: f64) -> Point {
+        Point::from_values(self.x / divisor, self.y / divisor, self.z / divisor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::from_values(-self.x, -self.y, -self.z)
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Mesh, Point, VertexIndex, FaceIndex};
+
+/// State of the mesh when a relaxation pass stopped
+#[derive(Copy, Clone, Debug)]
+pub struct RelaxReport {
+    pub iterations: usize,
+    /// The largest vertex movement of the last step fell below the tolerance
+    pub converged: bool,
+    /// Largest vertex movement of the last step
+    pub max_displacement: f64,
+}
+
+// Cheaper relaxation passes in the spirit of Hart's "adjust". Each step moves
+// every vertex by the average of the corrections of its faces or edges, and the
+// pass stops after `max_steps` or once no vertex moves more than `tolerance`.
+impl Mesh {
+    /// Projects the vertices onto the planes of their faces
+    pub fn planarize(&mut self, max_steps: usize, tolerance: f64) -> RelaxReport {
+        self.relax(max_steps, tolerance, Mesh::face_plane_corrections)
+    }
+
+    /// Moves the edge endpoints towards the mean edge length
+    pub fn equalize_edges(&mut self, max_steps: usize, tolerance: f64) -> RelaxReport {
+        self.relax(max_steps, tolerance, Mesh::edge_length_corrections)
+    }
+
+    fn relax(&mut self, max_steps: usize, tolerance: f64, corrections: fn(&Mesh) -> Vec<(Point, usize)>) -> RelaxReport {
+        let mut steps = 0;
+        let mut max_displacement = 0.0;
+        while steps < max_steps {
+            let before = self.vertex_positions();
+            let positions: Vec<Point> = before.iter()
+                .zip(corrections(self))
+                .map(|(position, (sum, count))| match count {
+                    0 => *position,
+                    _ => *position + sum / count as f64
+                })
+                .collect();
+            self.set_vertex_positions(&positions);
+            steps += 1;
+
+            max_displacement = self.max_change_from(&before);
+            if max_displacement < tolerance {
+                break;
+            }
+        }
+
+        RelaxReport {
+            iterations: steps,
+            converged: max_displacement < tolerance,
+            max_displacement
+        }
+    }
+
+    // offset of every vertex onto each of its face planes
+    fn face_plane_corrections(&self) -> Vec<(Point, usize)> {
+        let mut corrections = vec![(Point::new(), 0); self.vertex_count()];
+        for i in 0..self.face_count() {
+            let face = FaceIndex::new(i as u32);
+            let vertices: Vec<VertexIndex> = match self.face_vertices(face) {
+                Err(_) => continue,
+                Ok(vertices) => vertices.collect()
+            };
+
+            let normal = self.face_normal(face);
+            let center = vertices.iter()
+                .fold(Point::new(), |sum, v| sum + self.vertex(*v).location) / vertices.len() as f64;
+            for v in vertices {
+                let correction = &mut corrections[v.index as usize];
+                correction.0 += normal * normal.dot(center - self.vertex(v).location);
+                correction.1 += 1;
+            }
+        }
+
+        corrections
+    }
+
+    // half of the difference to the mean edge length, applied to both ends of every edge
+    fn edge_length_corrections(&self) -> Vec<(Point, usize)> {
+        let mut corrections = vec![(Point::new(), 0); self.vertex_count()];
+        let edges = self.edge_vertices();
+        if edges.is_empty() {
+            return corrections;
+        }
+
+        let vectors: Vec<Point> = edges.iter()
+            .map(|(start, end)| self.vertex(*end).location - self.vertex(*start).location)
+            .collect();
+        let target = vectors.iter().map(|v| v.length()).sum::<f64>() / vectors.len() as f64;
+
+        for ((start, end), vector) in edges.iter().zip(vectors) {
+            let correction = vector.normalize() * ((vector.length() - target) / 2.0);
+            corrections[start.index as usize].0 += correction;
+            corrections[start.index as usize].1 += 1;
+            corrections[end.index as usize].0 -= correction;
+            corrections[end.index as usize].1 += 1;
+        }
+
+        corrections
+    }
+}
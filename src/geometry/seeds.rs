@@ -0,0 +1,265 @@
+use super::{Mesh, Point, VertexIndex};
+
+// how close to zero the squared height of a seed may get before it counts as flat
+const FLAT_TOLERANCE: f64 = 1e-9;
+
+/// Size normalization of a seed polyhedron
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum SeedScale {
+    /// The vertex farthest from the vertex centroid lies on the unit sphere.
+    /// Only for vertex-transitive seeds are all vertices on it.
+    UnitCircumradius,
+    /// All edges have length one
+    UnitEdge,
+}
+
+// Platonic seeds, centred on the origin with faces wound counter-clockwise seen from outside
+impl Mesh {
+    /// Conway seed T
+    pub fn tetrahedron(scale: SeedScale) -> Mesh {
+        let points = [
+            (1.0, 1.0, 1.0), (1.0, -1.0, -1.0), (-1.0, 1.0, -1.0), (-1.0, -1.0, 1.0)];
+        let faces: [&[usize]; 4] = [
+            &[2, 0, 1], &[1, 0, 3], &[3, 0, 2], &[2, 1, 3]];
+
+        Mesh::from_seed(&points, &faces, scale)
+    }
+
+    /// Conway seed C
+    pub fn cube(scale: SeedScale) -> Mesh {
+        let points = [
+            (-1.0, -1.0, -1.0), (-1.0, -1.0, 1.0), (-1.0, 1.0, -1.0), (-1.0, 1.0, 1.0),
+            (1.0, -1.0, -1.0), (1.0, -1.0, 1.0), (1.0, 1.0, -1.0), (1.0, 1.0, 1.0)];
+        let faces: [&[usize]; 6] = [
+            &[2, 0, 1, 3], &[1, 0, 4, 5], &[4, 0, 2, 6],
+            &[3, 1, 5, 7], &[6, 2, 3, 7], &[5, 4, 6, 7]];
+
+        Mesh::from_seed(&points, &faces, scale)
+    }
+
+    /// Conway seed O
+    pub fn octahedron(scale: SeedScale) -> Mesh {
+        let points = [
+            (1.0, 0.0, 0.0), (-1.0, 0.0, 0.0), (0.0, 1.0, 0.0),
+            (0.0, -1.0, 0.0), (0.0, 0.0, 1.0), (0.0, 0.0, -1.0)];
+        let faces: [&[usize]; 8] = [
+            &[4, 0, 2], &[2, 0, 5], &[3, 0, 4], &[5, 0, 3],
+            &[2, 1, 4], &[5, 1, 2], &[4, 1, 3], &[3, 1, 5]];
+
+        Mesh::from_seed(&points, &faces, scale)
+    }
+
+    /// Conway seed D
+    pub fn dodecahedron(scale: SeedScale) -> Mesh {
+        let p = (1.0 + 5f64.sqrt()) / 2.0;
+        let q = 1.0 / p;
+        let points = [
+            (-1.0, -1.0, -1.0), (-1.0, -1.0, 1.0), (-1.0, 1.0, -1.0), (-1.0, 1.0, 1.0),
+            (1.0, -1.0, -1.0), (1.0, -1.0, 1.0), (1.0, 1.0, -1.0), (1.0, 1.0, 1.0),
+            (0.0, -q, -p), (-q, -p, 0.0), (-p, 0.0, -q), (0.0, -q, p),
+            (-q, p, 0.0), (p, 0.0, -q), (0.0, q, -p), (q, -p, 0.0),
+            (-p, 0.0, q), (0.0, q, p), (q, p, 0.0), (p, 0.0, q)];
+        let faces: [&[usize]; 12] = [
+            &[16, 10, 0, 9, 1], &[14, 8, 0, 10, 2], &[15, 9, 0, 8, 4],
+            &[3, 16, 1, 11, 17], &[5, 11, 1, 9, 15], &[3, 12, 2, 10, 16],
+            &[6, 14, 2, 12, 18], &[18, 12, 3, 17, 7], &[5, 15, 4, 13, 19],
+            &[6, 13, 4, 8, 14], &[17, 11, 5, 19, 7], &[19, 13, 6, 18, 7]];
+
+        Mesh::from_seed(&points, &faces, scale)
+    }
+
+    /// Conway seed I
+    pub fn icosahedron(scale: SeedScale) -> Mesh {
+        let p = (1.0 + 5f64.sqrt()) / 2.0;
+        let points = [
+            (0.0, -1.0, -p), (-1.0, -p, 0.0), (-p, 0.0, -1.0), (0.0, -1.0, p),
+            (-1.0, p, 0.0), (p, 0.0, -1.0), (0.0, 1.0, -p), (1.0, -p, 0.0),
+            (-p, 0.0, 1.0), (0.0, 1.0, p), (1.0, p, 0.0), (p, 0.0, 1.0)];
+        let faces: [&[usize]; 20] = [
+            &[2, 0, 1], &[1, 0, 7], &[6, 0, 2], &[5, 0, 6], &[7, 0, 5],
+            &[2, 1, 8], &[3, 1, 7], &[8, 1, 3], &[6, 2, 4], &[4, 2, 8],
+            &[11, 3, 7], &[8, 3, 9], &[9, 3, 11], &[6, 4, 10], &[9, 4, 8],
+            &[10, 4, 9], &[10, 5, 6], &[7, 5, 11], &[11, 5, 10], &[10, 9, 11]];
+
+        Mesh::from_seed(&points, &faces, scale)
+    }
+
+    // Recentres the points on their vertex centroid and scales them by the
+    // vertex farthest from the origin or by the first edge of the first face
+    fn from_seed<F: AsRef<[usize]>>(points: &[(f64, f64, f64)], faces: &[F], scale: SeedScale) -> Mesh {
+        let points: Vec<Point> = points.iter()
+            .map(|&(x, y, z)| Point::from_values(x, y, z))
+            .collect();
+        let center = points.iter().fold(Point::new(), |sum, p| sum + *p) / points.len() as f64;
+
+        let factor = match scale {
+            SeedScale::UnitCircumradius => 1.0 / points.iter().map(|p| p.distance(center)).fold(0.0, f64::max),
+            SeedScale::UnitEdge => {
+                let first = faces[0].as_ref();
+                1.0 / points[first[0]].distance(points[first[1]])
+            }
+        };
+
+        let mut mesh = Mesh::new();
+        for point in points {
+            mesh.add_vertex_position((point - center) * factor);
+        }
+        for face in faces {
+            mesh.add_face_by_indices(face.as_ref().iter().map(|i| VertexIndex::new(*i as u32)).collect()).unwrap();
+        }
+
+        mesh
+    }
+}
+
+// Parametric seeds, built with unit edges on the base polygon. Where regular
+// faces can not close up for large n, the height falls back to the edge length.
+// None for side counts below 3.
+impl Mesh {
+    /// Conway seed Pn, two n-gons joined by a band of n squares
+    pub fn prism(n: usize, scale: SeedScale) -> Option<Mesh> {
+        if n < 3 {
+            return None;
+        }
+
+        let r = circumradius(n);
+        let mut points = polygon(n, r, 0.0, -0.5);
+        points.extend(polygon(n, r, 0.0, 0.5));
+
+        let mut faces = vec![(0..n).rev().collect::<Vec<usize>>(), (n..2 * n).collect()];
+        for j in 0..n {
+            let k = (j + 1) % n;
+            faces.push(vec![j, k, n + k, n + j]);
+        }
+
+        Some(Mesh::from_seed(&points, &faces, scale))
+    }
+
+    /// Conway seed An, two n-gons joined by a band of 2n triangles
+    pub fn antiprism(n: usize, scale: SeedScale) -> Option<Mesh> {
+        if n < 3 {
+            return None;
+        }
+
+        let r = circumradius(n);
+        let chord = (std::f64::consts::PI / (2.0 * n as f64)).sin() / (std::f64::consts::PI / n as f64).sin();
+        let h = height(chord)?;
+        let mut points = polygon(n, r, 0.0, -h / 2.0);
+        points.extend(polygon(n, r, 0.5, h / 2.0));
+
+        let mut faces = vec![(0..n).rev().collect::<Vec<usize>>(), (n..2 * n).collect()];
+        for j in 0..n {
+            let k = (j + 1) % n;
+            faces.push(vec![j, k, n + j]);
+            faces.push(vec![n + j, k, n + k]);
+        }
+
+        Some(Mesh::from_seed(&points, &faces, scale))
+    }
+
+    /// Conway seed Yn, an n-gon with a triangle on each of its edges meeting in an apex.
+    /// None for n > 5, where unit-edge triangles can not reach over the base.
+    pub fn pyramid(n: usize, scale: SeedScale) -> Option<Mesh> {
+        if n < 3 {
+            return None;
+        }
+
+        let r = circumradius(n);
+        let mut points = polygon(n, r, 0.0, 0.0);
+        points.push((0.0, 0.0, height(r)?));
+
+        let mut faces = vec![(0..n).rev().collect::<Vec<usize>>()];
+        for j in 0..n {
+            faces.push(vec![j, (j + 1) % n, n]);
+        }
+
+        Some(Mesh::from_seed(&points, &faces, scale))
+    }
+
+    /// Extended seed Un, an n-gon joined to a 2n-gon by alternating squares and triangles.
+    /// None for n > 5, the hexagonal cupola is already flat.
+    pub fn cupola(n: usize, scale: SeedScale) -> Option<Mesh> {
+        if n < 3 {
+            return None;
+        }
+
+        let pi = std::f64::consts::PI;
+        let apothems = 1.0 / (2.0 * (pi / (2 * n) as f64).tan()) - 1.0 / (2.0 * (pi / n as f64).tan());
+        let mut points = polygon(n, circumradius(n), 0.0, height(apothems)?);
+        points.extend(polygon(2 * n, circumradius(2 * n), 0.5, 0.0));
+
+        let mut faces = vec![(0..n).collect::<Vec<usize>>(), (n..3 * n).rev().collect()];
+        for j in 0..n {
+            let k = (j + 1) % n;
+            faces.push(vec![n + 2 * j, n + 2 * j + 1, k, j]);
+            faces.push(vec![n + 2 * j + 1, n + (2 * j + 2) % (2 * n), k]);
+        }
+
+        Some(Mesh::from_seed(&points, &faces, scale))
+    }
+
+    /// Extended seed Vn, an n-gon joined to a 2n-gon by a band of 3n triangles.
+    /// None for n > 6, where the triangles can not span the band.
+    pub fn anticupola(n: usize, scale: SeedScale) -> Option<Mesh> {
+        if n < 3 {
+            return None;
+        }
+
+        let mut points = polygon(n, circumradius(n), 0.0, height(circumradius(2 * n) - circumradius(n))?);
+        points.extend(polygon(2 * n, circumradius(2 * n), 0.0, 0.0));
+
+        let mut faces = vec![(0..n).collect::<Vec<usize>>(), (n..3 * n).rev().collect()];
+        for j in 0..n {
+            let k = (j + 1) % n;
+            let below = n + 2 * j;
+            let before = n + (2 * j + 2 * n - 1) % (2 * n);
+            faces.push(vec![before, below, j]);
+            faces.push(vec![below, below + 1, j]);
+            faces.push(vec![j, below + 1, k]);
+        }
+
+        Some(Mesh::from_seed(&points, &faces, scale))
+    }
+
+    /// Extended seed Jn, the Johnson solid with catalogue number n.
+    /// Only the pyramids and cupolae J1 to J5 are available so far,
+    /// every other n returns None.
+    pub fn johnson(n: usize, scale: SeedScale) -> Option<Mesh> {
+        match n {
+            1 => Mesh::pyramid(4, scale),
+            2 => Mesh::pyramid(5, scale),
+            3 => Mesh::cupola(3, scale),
+            4 => Mesh::cupola(4, scale),
+            5 => Mesh::cupola(5, scale),
+            _ => None
+        }
+    }
+}
+
+// circumradius of the regular n-gon with unit edges
+fn circumradius(n: usize) -> f64 {
+    1.0 / (2.0 * (std::f64::consts::PI / n as f64).sin())
+}
+
+// height over which a unit edge spans the given horizontal distance, None if
+// the edge is too short to span it and the solid would be flat or cannot close
+fn height(distance: f64) -> Option<f64> {
+    let squared = 1.0 - distance * distance;
+    if squared > FLAT_TOLERANCE {
+        Some(squared.sqrt())
+    }
+    else {
+        None
+    }
+}
+
+// regular n-gon at height z, counter-clockwise seen from above, the first
+// vertex rotated by `offset` steps from the x-axis
+fn polygon(n: usize, radius: f64, offset: f64, z: f64) -> Vec<(f64, f64, f64)> {
+    (0..n)
+        .map(|j| {
+            let angle = 2.0 * std::f64::consts::PI * (j as f64 + offset) / n as f64;
+            (radius * angle.cos(), radius * angle.sin(), z)
+        })
+        .collect()
+}
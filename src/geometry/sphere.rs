@@ -0,0 +1,65 @@
+use super::{Mesh, Point, VertexIndex, FaceIndex, MeshError, UnsetValue};
+
+// how close a face plane may come to the center before its pole is rejected
+const PLANE_TOLERANCE: f64 = 1e-12;
+
+impl Mesh {
+    /// Moves every vertex along the ray from `center` onto the sphere of the given radius.
+    /// A vertex sitting on the center has no direction and stays where it is.
+    pub fn project_to_sphere(&mut self, radius: f64, center: Point) {
+        for i in 0..self.vertex_count() {
+            let index = VertexIndex::new(i as u32);
+            let offset = self.vertex(index).location - center;
+            if offset.length() > f64::EPSILON {
+                self.set_vertex_position(index, center + offset.normalize() * radius);
+            }
+        }
+    }
+
+    /// The geometric dual by polar reciprocation about the sphere of the given radius.
+    /// Every face becomes a vertex, the pole of the face plane, and every closed vertex
+    /// becomes a face. Faces removed but not yet compacted are skipped, vertices next
+    /// to them are not closed. Reciprocating twice about the same sphere restores planar meshes.
+    /// Fails if a face plane passes through the center, its pole lies at infinity.
+    pub fn reciprocal(&self, radius: f64, center: Point) -> Result<Mesh, MeshError> {
+        let mut dual = Mesh::new();
+        let mut poles = vec![VertexIndex::unset(); self.face_count()];
+        for (i, pole) in poles.iter_mut().enumerate() {
+            let face = FaceIndex::new(i as u32);
+            if self.face(face).is_unused() {
+                continue;
+            }
+
+            let points: Vec<Point> = self.face_vertices(face)?
+                .map(|vertex| self.vertex(vertex).location)
+                .collect();
+            let centroid = points.iter().fold(Point::new(), |sum, p| sum + *p) / points.len() as f64;
+            let normal = self.face_normal(face);
+
+            let distance = (centroid - center).dot(normal);
+            if distance.abs() < PLANE_TOLERANCE {
+                return Err(MeshError::PlaneThroughCenter(face));
+            }
+            *pole = dual.add_vertex_position(center + normal * (radius * radius / distance));
+        }
+
+        for i in 0..self.vertex_count() {
+            let circulator = match self.vertex_circulator(VertexIndex::new(i as u32)) {
+                Err(MeshError::IsolatedVertex(_)) => continue,
+                Err(error) => return Err(error),
+                Ok(circulator) => circulator
+            };
+            let faces: Vec<FaceIndex> = circulator
+                .map(|edge| edge.map(|edge| self.half_edge(edge).adjacent_face))
+                .collect::<Result<_, _>>()?;
+            if faces.iter().any(|face| face.is_unset()) {
+                continue;
+            }
+
+            // the circulator runs clockwise seen from outside
+            dual.add_face_by_indices(faces.iter().rev().map(|face| poles[face.index as usize]).collect())?;
+        }
+
+        Ok(dual)
+    }
+}
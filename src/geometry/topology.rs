@@ -0,0 +1,75 @@
+use super::{Mesh, VertexIndex, FaceIndex, HalfEdgeIndex, HalfEdgeCollection, MeshError, UnsetValue};
+use std::iter::once;
+
+// Adjacency queries built on the circulators. Each loop is walked completely
+// before the iterator is returned, so a broken half-edge loop is reported as
+// an error instead of showing up as a shortened list.
+impl Mesh {
+    /// Vertices of the face, counter-clockwise seen from outside
+    pub fn face_vertices(&self, index: FaceIndex) -> Result<impl Iterator<Item = VertexIndex> + '_, MeshError> {
+        Ok(self.face_edges(index)?.map(move |edge| self.half_edge(edge).start_vertex))
+    }
+
+    /// Half-edges of the face, each starting at the vertex of the same position in `face_vertices`
+    pub fn face_edges(&self, index: FaceIndex) -> Result<impl Iterator<Item = HalfEdgeIndex> + '_, MeshError> {
+        let edges: Vec<HalfEdgeIndex> = self.face_circulator(index)?.collect::<Result<_, _>>()?;
+        Ok(edges.into_iter())
+    }
+
+    pub fn face_degree(&self, index: FaceIndex) -> Result<usize, MeshError> {
+        Ok(self.face_edges(index)?.count())
+    }
+
+    /// Outgoing half-edges of the vertex, in circulator order (clockwise seen from outside).
+    /// Isolated vertices have none.
+    pub fn vertex_edges(&self, index: VertexIndex) -> Result<impl Iterator<Item = HalfEdgeIndex> + '_, MeshError> {
+        let edges: Vec<HalfEdgeIndex> = match self.vertex_circulator(index) {
+            Err(MeshError::IsolatedVertex(_)) => Vec::new(),
+            circulator => circulator?.collect::<Result<_, _>>()?
+        };
+        Ok(edges.into_iter())
+    }
+
+    /// Faces around the vertex, skipping the gaps of a boundary
+    pub fn vertex_faces(&self, index: VertexIndex) -> Result<impl Iterator<Item = FaceIndex> + '_, MeshError> {
+        Ok(self.vertex_edges(index)?
+            .map(move |edge| self.half_edge(edge).adjacent_face)
+            .filter(|face| !face.is_unset()))
+    }
+
+    /// Neighbouring vertices connected to the vertex by an edge, its one-ring
+    pub fn vertex_neighbours(&self, index: VertexIndex) -> Result<impl Iterator<Item = VertexIndex> + '_, MeshError> {
+        Ok(self.vertex_edges(index)?.map(move |edge| self.find_end_vertex_index(edge)))
+    }
+
+    pub fn vertex_degree(&self, index: VertexIndex) -> Result<usize, MeshError> {
+        Ok(self.vertex_edges(index)?.count())
+    }
+
+    /// The faces on both sides of the half-edge, one for a boundary edge
+    pub fn edge_faces(&self, index: HalfEdgeIndex) -> Result<impl Iterator<Item = FaceIndex>, MeshError> {
+        if index.index >= self.half_edge_count() as u32 {
+            return Err(MeshError::IndexOutOfRange(index.index));
+        }
+
+        let pair = HalfEdgeCollection::edge_pair_index(index);
+        Ok(once(self.half_edge(index).adjacent_face)
+            .chain(once(self.half_edge(pair).adjacent_face))
+            .filter(|face| !face.is_unset()))
+    }
+
+    /// The vertex of the triangle adjacent to the half-edge that does not lie on it.
+    /// None if the half-edge has no face or the face is not a triangle.
+    pub fn opposite_vertex(&self, index: HalfEdgeIndex) -> Result<Option<VertexIndex>, MeshError> {
+        if index.index >= self.half_edge_count() as u32 {
+            return Err(MeshError::IndexOutOfRange(index.index));
+        }
+
+        let face = self.half_edge(index).adjacent_face;
+        if face.is_unset() || self.face_degree(face)? != 3 {
+            return Ok(None);
+        }
+
+        Ok(Some(self.half_edge(self.half_edge(index).previous_edge).start_vertex))
+    }
+}
@@ -3,6 +3,9 @@
 pub trait MeshPartCollection<T, U> {
     fn new() -> Self;
     fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     fn add(&mut self, element: T) -> U;
 }
 
@@ -19,7 +19,7 @@ impl UnsetValue for VertexIndex {
 
 impl VertexIndex {
     pub fn new(index: u32) -> VertexIndex {
-        VertexIndex {index: index}
+        VertexIndex {index}
     }
 
     pub fn increment(&mut self) {
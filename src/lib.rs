@@ -1,4 +1,7 @@
-mod tests;
-mod geometry;
-mod operations;
+#[macro_use]
+mod logging;
 
+mod tests;
+pub mod geometry;
+pub mod operations;
+pub mod conway;
@@ -0,0 +1,23 @@
+// Diagnostic output goes through the `log` facade when the `log` feature is
+// enabled. Without it the macros compile to nothing, but still type-check
+// their arguments so both configurations see the same code.
+
+#[cfg(feature = "log")]
+macro_rules! trace {
+    ($($arg:tt)*) => { log::trace!($($arg)*) };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! trace {
+    ($($arg:tt)*) => { if false { let _ = format_args!($($arg)*); } };
+}
+
+#[cfg(feature = "log")]
+macro_rules! debug {
+    ($($arg:tt)*) => { log::debug!($($arg)*) };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! debug {
+    ($($arg:tt)*) => { if false { let _ = format_args!($($arg)*); } };
+}
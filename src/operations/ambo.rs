@@ -0,0 +1,11 @@
+use super::{Ambo, Operator, ChamberPattern};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Ambo for Mesh {
+    /// Builds the rectified mesh: every edge becomes a vertex at its midpoint,
+    /// every face shrinks to the midpoints of its edges and every vertex
+    /// is cut off by a new face through the midpoints of its outgoing edges.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        ChamberPattern::ambo().apply(self)
+    }
+}
@@ -0,0 +1,10 @@
+use super::{Bevel, Truncate, Ambo};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Bevel for Mesh {
+    /// Builds the bevel, the truncated ambo: faces and vertices both become
+    /// faces with twice their degree and every edge becomes a quadrilateral.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        Truncate::compute(&Ambo::compute(self)?, None, 1.0 / 3.0)
+    }
+}
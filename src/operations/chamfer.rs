@@ -0,0 +1,58 @@
+use super::{Chamfer, FaceCenterMethod};
+use super::super::geometry::{Mesh, MeshError, Point, VertexIndex, FaceIndex, HalfEdgeIndex, HalfEdgeCollection, UnsetValue};
+
+impl Chamfer for Mesh {
+    /// Builds the chamfer: every face shrinks towards its center by `depth`
+    /// and every edge is replaced by a hexagon joining the shrunken faces.
+    fn compute(&self, depth: f64) -> Result<Mesh, MeshError> {
+        let mut chamfer = Mesh::new();
+        for i in 0..self.vertex_count() {
+            chamfer.add_vertex_position(self.vertex(VertexIndex::new(i as u32)).location);
+        }
+
+        // one vertex per half-edge, in the corner of its face at its start vertex
+        let corner_offset = self.vertex_count() as u32;
+        for i in 0..self.half_edge_count() {
+            chamfer.add_vertex_position(face_corner(self, HalfEdgeIndex::new(i as u32), depth));
+        }
+        let corner = |index: HalfEdgeIndex| VertexIndex::new(corner_offset + index.index);
+
+        // shrunken original faces
+        for i in 0..self.face_count() {
+            if let Ok(edges) = self.face_edges(FaceIndex::new(i as u32)) {
+                chamfer.add_face_by_indices(edges.map(corner).collect())?;
+            }
+        }
+
+        // one hexagon per half-edge pair
+        for edge in self.edges() {
+            let index = edge.half_edge();
+            let pair = HalfEdgeCollection::edge_pair_index(index);
+            if self.half_edge(index).adjacent_face.is_unset() || self.half_edge(pair).adjacent_face.is_unset() {
+                continue;
+            }
+
+            chamfer.add_face_by_indices(vec![
+                self.half_edge(index).start_vertex,
+                corner(self.half_edge(pair).next_edge),
+                corner(pair),
+                self.half_edge(pair).start_vertex,
+                corner(self.half_edge(index).next_edge),
+                corner(index)])?;
+        }
+
+        Ok(chamfer)
+    }
+}
+
+// start vertex of the half-edge moved towards the center of its face by `ratio`
+pub fn face_corner(mesh: &Mesh, index: HalfEdgeIndex, ratio: f64) -> Point {
+    let location = mesh.vertex(mesh.half_edge(index).start_vertex).location;
+    let face = mesh.half_edge(index).adjacent_face;
+    if face.is_unset() {
+        return location;
+    }
+
+    let center = mesh.face_center(face, FaceCenterMethod::Centroid);
+    location.lerp(center, ratio)
+}
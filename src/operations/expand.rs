@@ -0,0 +1,10 @@
+use super::{Expand, Operator, ChamberPattern};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Expand for Mesh {
+    /// Builds the expansion: faces move apart, leaving a quadrilateral for
+    /// every edge and a new face for every vertex. Same as ambo applied twice.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        ChamberPattern::expand().apply(self)
+    }
+}
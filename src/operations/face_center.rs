@@ -0,0 +1,66 @@
+use super::FaceCenterMethod;
+use super::super::geometry::{Mesh, Point, FaceIndex, newell_vector};
+
+impl Mesh {
+    /// Computes the center of the face at `index` with the given method.
+    /// Faces without a valid half-edge loop return the origin.
+    pub fn face_center(&self, index: FaceIndex, method: FaceCenterMethod) -> Point {
+        let points = face_points(self, index);
+        if points.is_empty() {
+            return Point::new();
+        }
+
+        match method {
+            FaceCenterMethod::Centroid => centroid(&points),
+            FaceCenterMethod::AreaCentroid => area_centroid(&points),
+            FaceCenterMethod::Tangent => tangent_point(&points)
+        }
+    }
+}
+
+fn face_points(mesh: &Mesh, index: FaceIndex) -> Vec<Point> {
+    match mesh.face_vertices(index) {
+        Err(_) => Vec::new(),
+        Ok(vertices) => vertices.map(|vertex| mesh.vertex(vertex).location).collect()
+    }
+}
+
+fn centroid(points: &[Point]) -> Point {
+    let sum = points.iter().fold(Point::new(), |sum, point| sum + *point);
+    sum / points.len() as f64
+}
+
+fn area_centroid(points: &[Point]) -> Point {
+    let center = centroid(points);
+    let normal = newell_vector(points);
+
+    // fan of triangles around the vertex centroid, weighted by signed area
+    let n = points.len();
+    let mut weighted = Point::new();
+    let mut total = 0.0;
+    for i in 0..n {
+        let a = points[i];
+        let b = points[(i + 1) % n];
+        let area = (a - center).cross(b - center).dot(normal);
+        weighted += (center + a + b) * (area / 3.0);
+        total += area;
+    }
+
+    if total.abs() < f64::EPSILON {
+        return center;
+    }
+    weighted / total
+}
+
+fn tangent_point(points: &[Point]) -> Point {
+    let center = centroid(points);
+    let normal = newell_vector(points);
+    let normal_length = normal.length();
+    if normal_length < f64::EPSILON {
+        return center;
+    }
+
+    // foot of the perpendicular from the origin onto the face plane
+    let unit = normal / normal_length;
+    unit * center.dot(unit)
+}
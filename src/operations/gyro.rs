@@ -0,0 +1,13 @@
+use super::{Gyro, Handedness, Operator, ChamberPattern, Mirrored};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Gyro for Mesh {
+    /// Builds the gyro: every face is split into pentagons, one per edge,
+    /// spiralling around the face center. Dual of snub, chiral.
+    fn compute(&self, handedness: Handedness) -> Result<Mesh, MeshError> {
+        match handedness {
+            Handedness::Right => ChamberPattern::gyro().apply(self),
+            Handedness::Left => Mirrored(ChamberPattern::gyro()).apply(self)
+        }
+    }
+}
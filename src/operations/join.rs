@@ -0,0 +1,10 @@
+use super::{Join, Operator, ChamberPattern};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Join for Mesh {
+    /// Builds the join: the original vertices plus one vertex per face center,
+    /// with a quadrilateral across every original edge. Dual of ambo.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        ChamberPattern::join().apply(self)
+    }
+}
@@ -0,0 +1,42 @@
+use super::{Kis, FaceCenterMethod};
+use super::super::geometry::{Mesh, MeshError, Point, VertexIndex, FaceIndex};
+
+impl Kis for Mesh {
+    /// Raises a pyramid on every face with `degree` half-edges, or on every
+    /// face if no degree is given. The apex sits at the face centroid,
+    /// moved by `height` along the face normal.
+    fn compute(&self, degree: Option<usize>, height: f64) -> Result<Mesh, MeshError> {
+        let mut kis = Mesh::new();
+
+        // original vertices keep their indices
+        for i in 0..self.vertex_count() {
+            kis.add_vertex_position(self.vertex(VertexIndex::new(i as u32)).location);
+        }
+
+        for i in 0..self.face_count() {
+            let face_index = FaceIndex::new(i as u32);
+            let vertices: Vec<VertexIndex> = match self.face_vertices(face_index) {
+                Err(_) => continue,
+                Ok(vertices) => vertices.collect()
+            };
+
+            if degree.is_some() && degree != Some(vertices.len()) {
+                kis.add_face_by_indices(vertices)?;
+                continue;
+            }
+
+            let apex = kis.add_vertex_position(apex_position(self, face_index, height));
+            let n = vertices.len();
+            for j in 0..n {
+                kis.add_face_by_indices(vec![vertices[j], vertices[(j + 1) % n], apex])?;
+            }
+        }
+
+        Ok(kis)
+    }
+}
+
+fn apex_position(mesh: &Mesh, index: FaceIndex, height: f64) -> Point {
+    let center = mesh.face_center(index, FaceCenterMethod::Centroid);
+    center + mesh.face_normal(index) * height
+}
@@ -0,0 +1,41 @@
+use super::Loft;
+use super::chamfer::face_corner;
+use super::super::geometry::{Mesh, MeshError, VertexIndex, FaceIndex, HalfEdgeIndex};
+
+impl Loft for Mesh {
+    /// Builds the loft: every face is extruded into a prism with a shrunken
+    /// top face, raised by `height` along the face normal.
+    fn compute(&self, height: f64) -> Result<Mesh, MeshError> {
+        let mut loft = Mesh::new();
+        for i in 0..self.vertex_count() {
+            loft.add_vertex_position(self.vertex(VertexIndex::new(i as u32)).location);
+        }
+
+        for i in 0..self.face_count() {
+            let face_index = FaceIndex::new(i as u32);
+            let edges: Vec<HalfEdgeIndex> = match self.face_circulator(face_index).and_then(Iterator::collect) {
+                Err(_) => continue,
+                Ok(edges) => edges
+            };
+
+            let offset = self.face_normal(face_index) * height;
+
+            let top: Vec<VertexIndex> = edges.iter()
+                .map(|index| loft.add_vertex_position(face_corner(self, *index, 1.0 / 3.0) + offset))
+                .collect();
+            loft.add_face_by_indices(top.clone())?;
+
+            let n = edges.len();
+            for j in 0..n {
+                let index = edges[j];
+                loft.add_face_by_indices(vec![
+                    self.half_edge(index).start_vertex,
+                    self.find_end_vertex_index(index),
+                    top[(j + 1) % n],
+                    top[j]])?;
+            }
+        }
+
+        Ok(loft)
+    }
+}
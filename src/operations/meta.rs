@@ -0,0 +1,10 @@
+use super::{Meta, Kis, Join};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Meta for Mesh {
+    /// Builds the meta, the kis of the join: every face is split into
+    /// triangles around its center, two per original edge.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        Kis::compute(&Join::compute(self)?, None, 0.0)
+    }
+}
@@ -0,0 +1,19 @@
+use super::super::geometry::{Mesh, VertexIndex, FaceIndex, MeshError};
+
+/// Copy of the mesh with every face wound the other way round. Applying
+/// a chiral operation in between two reversals yields its mirror image.
+pub fn reversed(mesh: &Mesh) -> Result<Mesh, MeshError> {
+    let mut result = Mesh::new();
+    for i in 0..mesh.vertex_count() {
+        result.add_vertex_position(mesh.vertex(VertexIndex::new(i as u32)).location);
+    }
+    for i in 0..mesh.face_count() {
+        if let Ok(vertices) = mesh.face_vertices(FaceIndex::new(i as u32)) {
+            let mut face: Vec<VertexIndex> = vertices.collect();
+            face.reverse();
+            result.add_face_by_indices(face)?;
+        }
+    }
+
+    Ok(result)
+}
@@ -1,9 +1,117 @@
-use super::geometry::Point;
+use super::geometry::{Mesh, MeshError};
 
-pub trait FaceCenter{
-    fn compute(&self) -> Point; 
+mod face_center;
+mod ambo;
+mod kis;
+mod truncate;
+mod mirror;
+mod join;
+mod ortho;
+mod expand;
+mod gyro;
+mod snub;
+mod chamfer;
+mod bevel;
+mod meta;
+mod needle;
+mod zip;
+mod loft;
+mod whirl;
+mod propeller;
+mod quinto;
+
+pub use self::pattern::{ChamberPattern, ChamberPoint, FaceRule, PointRef, Step, Mirrored, PatternError};
+mod pattern;
+
+/// Ways to place the center point of a face
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum FaceCenterMethod {
+    /// Average of the face vertices
+    Centroid,
+    /// Centroid of the polygon area, not biased by vertex spacing
+    AreaCentroid,
+    /// Point where the face plane touches a sphere around the origin,
+    /// the polar reciprocal center of the face
+    Tangent,
+}
+
+/// Orientation of the twist added by a chiral operation
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Handedness {
+    Left,
+    Right,
+}
+
+/// Operation that builds a new mesh from an existing one,
+/// fails if the faces it builds do not fit together
+pub trait Operator{
+    fn apply(&self, mesh: &Mesh) -> Result<Mesh, MeshError>;
+}
+
+pub trait Ambo{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
+
+pub trait Kis{
+    fn compute(&self, degree: Option<usize>, height: f64) -> Result<Mesh, MeshError>;
+}
+
+pub trait Truncate{
+    fn compute(&self, degree: Option<usize>, ratio: f64) -> Result<Mesh, MeshError>;
+}
+
+pub trait Join{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
+
+pub trait Ortho{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
+
+pub trait Expand{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
+
+pub trait Gyro{
+    fn compute(&self, handedness: Handedness) -> Result<Mesh, MeshError>;
 }
 
-pub trait Dual{
-    fn compute(&self) -> Self;
-}
\ No newline at end of file
+pub trait Snub{
+    fn compute(&self, handedness: Handedness) -> Result<Mesh, MeshError>;
+}
+
+pub trait Chamfer{
+    fn compute(&self, depth: f64) -> Result<Mesh, MeshError>;
+}
+
+pub trait Bevel{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
+
+pub trait Meta{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
+
+pub trait Needle{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
+
+pub trait Zip{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
+
+pub trait Loft{
+    fn compute(&self, height: f64) -> Result<Mesh, MeshError>;
+}
+
+pub trait Whirl{
+    fn compute(&self, handedness: Handedness) -> Result<Mesh, MeshError>;
+}
+
+pub trait Propeller{
+    fn compute(&self, handedness: Handedness) -> Result<Mesh, MeshError>;
+}
+
+pub trait Quinto{
+    fn compute(&self) -> Result<Mesh, MeshError>;
+}
@@ -0,0 +1,10 @@
+use super::{Needle, Kis, Operator, ChamberPattern};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Needle for Mesh {
+    /// Builds the needle, the kis of the dual: one triangle for every
+    /// corner of every original vertex.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        Kis::compute(&ChamberPattern::dual().apply(self)?, None, 0.0)
+    }
+}
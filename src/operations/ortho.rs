@@ -0,0 +1,10 @@
+use super::{Ortho, Operator, ChamberPattern};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Ortho for Mesh {
+    /// Builds the ortho: every face is split into quadrilaterals, one per
+    /// corner, joining the corner, its two edge midpoints and the face center.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        ChamberPattern::ortho().apply(self)
+    }
+}
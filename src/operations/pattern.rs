@@ -0,0 +1,397 @@
+use super::{Operator, FaceCenterMethod};
+use super::mirror::reversed;
+use super::super::geometry::{Mesh, Point, VertexIndex, FaceIndex, HalfEdgeIndex, HalfEdgeCollection, MeshError, UnsetValue};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Point of an operator pattern, placed in the triangle spanned by a
+/// half-edge and the center of its face. The triangle is made of the two
+/// chambers (vertex, edge, face) on either side of the edge midpoint.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum ChamberPoint {
+    /// The start vertex of the half-edge, shared by all triangles around it
+    Vertex,
+    /// The face center, shared by all triangles of the face
+    Face,
+    /// The edge midpoint, shared with the triangle of the paired half-edge
+    Midpoint,
+    /// A point on the half-edge at the given fraction from its start,
+    /// strictly between 0 and 0.5. The point at 1 - t is reached from the paired half-edge.
+    Edge(f64),
+    /// A point with barycentric weights for the start vertex, the end
+    /// vertex and the face center, owned by this triangle alone. The weights
+    /// are finite, not negative and have a positive sum.
+    Inner(f64, f64, f64),
+}
+
+/// Step from one half-edge triangle to a neighbouring one
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Step {
+    Next,
+    Previous,
+    Pair,
+}
+
+/// Pattern point `point` of the triangle reached by walking `path`
+#[derive(PartialEq, Clone, Debug)]
+pub struct PointRef {
+    pub path: Vec<Step>,
+    pub point: usize,
+}
+
+impl PointRef {
+    pub fn new(path: &[Step], point: usize) -> PointRef {
+        PointRef {
+            path: path.to_vec(),
+            point
+        }
+    }
+}
+
+/// How the faces of the result are made from pattern points
+#[derive(PartialEq, Clone, Debug)]
+pub enum FaceRule {
+    /// One face per original face, the points are taken from each of its half-edges in order
+    AroundFace(Vec<PointRef>),
+    /// One face per original vertex, the points are taken from each outgoing
+    /// half-edge, counter-clockwise seen from outside
+    AroundVertex(Vec<PointRef>),
+    /// One face per half-edge
+    PerHalfEdge(Vec<PointRef>),
+    /// One face per half-edge pair, seen from the even half-edge
+    PerEdge(Vec<PointRef>),
+}
+
+/// Conway operator described by data alone: the points it places in each
+/// half-edge triangle and the faces it builds from them. Faces touching a
+/// boundary are skipped.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ChamberPattern {
+    points: Vec<ChamberPoint>,
+    faces: Vec<FaceRule>,
+    center: FaceCenterMethod,
+}
+
+/// Error in the data of a chamber pattern
+#[derive(PartialEq, Clone, Debug)]
+pub enum PatternError {
+    /// The edge point at this position does not lie strictly between 0 and 0.5
+    EdgeFraction(usize),
+    /// A point reference names a point the pattern does not have
+    PointOutOfRange(usize),
+    /// The inner point at this position has a negative or non-finite weight, or weights summing to zero
+    InnerWeights(usize),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::EdgeFraction(point) => write!(f, "Edge point {} has to lie strictly between 0 and 0.5", point),
+            PatternError::PointOutOfRange(point) => write!(f, "Pattern has no point {}", point),
+            PatternError::InnerWeights(point) => write!(f, "Inner point {} needs finite, non-negative weights with a positive sum", point)
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl ChamberPattern {
+    /// Checks the pattern data before it can be applied
+    pub fn new(points: Vec<ChamberPoint>, faces: Vec<FaceRule>, center: FaceCenterMethod) -> Result<ChamberPattern, PatternError> {
+        for (i, point) in points.iter().enumerate() {
+            match *point {
+                ChamberPoint::Edge(t) if !(t > 0.0 && t < 0.5) => return Err(PatternError::EdgeFraction(i)),
+                ChamberPoint::Inner(a, b, c) => {
+                    let weights = [a, b, c];
+                    if weights.iter().any(|w| !w.is_finite() || *w < 0.0) || weights.iter().sum::<f64>() <= 0.0 {
+                        return Err(PatternError::InnerWeights(i));
+                    }
+                }
+                _ => ()
+            }
+        }
+
+        let missing = faces.iter()
+            .flat_map(|rule| match rule {
+                FaceRule::AroundFace(refs) | FaceRule::AroundVertex(refs) |
+                FaceRule::PerHalfEdge(refs) | FaceRule::PerEdge(refs) => refs.iter()
+            })
+            .find(|point_ref| point_ref.point >= points.len());
+        if let Some(point_ref) = missing {
+            return Err(PatternError::PointOutOfRange(point_ref.point));
+        }
+
+        Ok(ChamberPattern { points, faces, center })
+    }
+}
+
+/// Mirror image of a chiral operator
+pub struct Mirrored<T: Operator>(pub T);
+
+impl<T: Operator> Operator for Mirrored<T> {
+    fn apply(&self, mesh: &Mesh) -> Result<Mesh, MeshError> {
+        reversed(&self.0.apply(&reversed(mesh)?)?)
+    }
+}
+
+impl Operator for ChamberPattern {
+    fn apply(&self, mesh: &Mesh) -> Result<Mesh, MeshError> {
+        let mut builder = Builder {
+            mesh,
+            pattern: self,
+            result: Mesh::new(),
+            created: HashMap::new()
+        };
+
+        for rule in &self.faces {
+            match rule {
+                FaceRule::AroundFace(refs) => {
+                    for i in 0..mesh.face_count() {
+                        let edges: Result<Vec<HalfEdgeIndex>, MeshError> = mesh.face_circulator(FaceIndex::new(i as u32)).and_then(Iterator::collect);
+                        if let Ok(edges) = edges {
+                            builder.add_face(&edges, refs)?;
+                        }
+                    }
+                }
+                FaceRule::AroundVertex(refs) => {
+                    for i in 0..mesh.vertex_count() {
+                        let edges: Result<Vec<HalfEdgeIndex>, MeshError> = mesh.vertex_circulator(VertexIndex::new(i as u32)).and_then(Iterator::collect);
+                        if let Ok(mut edges) = edges {
+                            // the vertex circulator walks clockwise
+                            edges.reverse();
+                            builder.add_face(&edges, refs)?;
+                        }
+                    }
+                }
+                FaceRule::PerHalfEdge(refs) => {
+                    for i in 0..mesh.half_edge_count() {
+                        builder.add_face(&[HalfEdgeIndex::new(i as u32)], refs)?;
+                    }
+                }
+                FaceRule::PerEdge(refs) => {
+                    for edge in mesh.edges() {
+                        builder.add_face(&[edge.half_edge()], refs)?;
+                    }
+                }
+            }
+        }
+
+        Ok(builder.result)
+    }
+}
+
+struct Builder<'a> {
+    mesh: &'a Mesh,
+    pattern: &'a ChamberPattern,
+    result: Mesh,
+    // (pattern point, owner index) -> vertex in the result
+    created: HashMap<(usize, u32), VertexIndex>,
+}
+
+impl<'a> Builder<'a> {
+    // resolves the refs for every given half-edge in turn, skips the face if any touches a boundary
+    fn add_face(&mut self, edges: &[HalfEdgeIndex], refs: &[PointRef]) -> Result<(), MeshError> {
+        let mut indices: Vec<VertexIndex> = Vec::with_capacity(edges.len() * refs.len());
+        for edge in edges {
+            for point_ref in refs {
+                let mut index = *edge;
+                for step in &point_ref.path {
+                    if self.mesh.half_edge(index).adjacent_face.is_unset() {
+                        return Ok(());
+                    }
+                    index = match step {
+                        Step::Next => self.mesh.half_edge(index).next_edge,
+                        Step::Previous => self.mesh.half_edge(index).previous_edge,
+                        Step::Pair => HalfEdgeCollection::edge_pair_index(index)
+                    };
+                }
+                if self.mesh.half_edge(index).adjacent_face.is_unset() {
+                    return Ok(());
+                }
+                indices.push(self.vertex(index, point_ref.point));
+            }
+        }
+
+        self.result.add_face_by_indices(indices)?;
+        Ok(())
+    }
+
+    fn vertex(&mut self, index: HalfEdgeIndex, point: usize) -> VertexIndex {
+        let edge = self.mesh.half_edge(index);
+        let owner = match self.pattern.points[point] {
+            ChamberPoint::Vertex => edge.start_vertex.index,
+            ChamberPoint::Face => edge.adjacent_face.index,
+            ChamberPoint::Midpoint => index.index / 2,
+            ChamberPoint::Edge(_) | ChamberPoint::Inner(_, _, _) => index.index
+        };
+
+        if let Some(vertex) = self.created.get(&(point, owner)) {
+            return *vertex;
+        }
+        let vertex = self.result.add_vertex_position(self.position(index, point));
+        self.created.insert((point, owner), vertex);
+        vertex
+    }
+
+    fn position(&self, index: HalfEdgeIndex, point: usize) -> Point {
+        let edge = self.mesh.half_edge(index);
+        let start = self.mesh.vertex(edge.start_vertex).location;
+        let end = self.mesh.vertex(self.mesh.find_end_vertex_index(index)).location;
+        let center = || self.mesh.face_center(edge.adjacent_face, self.pattern.center);
+
+        let (a, b, c) = match self.pattern.points[point] {
+            ChamberPoint::Vertex => return start,
+            ChamberPoint::Face => return center(),
+            ChamberPoint::Midpoint => (0.5, 0.5, 0.0),
+            ChamberPoint::Edge(t) => (1.0 - t, t, 0.0),
+            ChamberPoint::Inner(a, b, c) => (a, b, c)
+        };
+        let center = if c == 0.0 { Point::new() } else { center() };
+
+        let weight = a + b + c;
+        (start * a + end * b + center * c) / weight
+    }
+}
+
+// point a third along the half-edge, seen from its start vertex
+const EDGE_THIRD: ChamberPoint = ChamberPoint::Edge(1.0 / 3.0);
+
+// The common operators as patterns, the parameter free operation traits
+// apply these directly. Chiral ones are right handed
+impl ChamberPattern {
+    pub fn dual() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Face],
+            faces: vec![FaceRule::AroundVertex(vec![PointRef::new(&[], 0)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    pub fn ambo() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Midpoint],
+            faces: vec![
+                FaceRule::AroundFace(vec![PointRef::new(&[], 0)]),
+                FaceRule::AroundVertex(vec![PointRef::new(&[], 0)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    /// Kis without degree filter or apex height, see `Kis` for those
+    pub fn kis() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Vertex, ChamberPoint::Face],
+            faces: vec![FaceRule::PerHalfEdge(vec![
+                PointRef::new(&[], 0), PointRef::new(&[Step::Next], 0), PointRef::new(&[], 1)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    pub fn join() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Vertex, ChamberPoint::Face],
+            faces: vec![FaceRule::PerEdge(vec![
+                PointRef::new(&[], 0), PointRef::new(&[Step::Pair], 1),
+                PointRef::new(&[Step::Pair], 0), PointRef::new(&[], 1)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    /// Truncate at a third without degree filter, see `Truncate` for those
+    pub fn truncate() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![EDGE_THIRD],
+            faces: vec![
+                FaceRule::AroundFace(vec![PointRef::new(&[], 0), PointRef::new(&[Step::Pair], 0)]),
+                FaceRule::AroundVertex(vec![PointRef::new(&[], 0)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    pub fn ortho() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Vertex, ChamberPoint::Midpoint, ChamberPoint::Face],
+            faces: vec![FaceRule::PerHalfEdge(vec![
+                PointRef::new(&[], 0), PointRef::new(&[], 1),
+                PointRef::new(&[], 2), PointRef::new(&[Step::Previous], 1)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    pub fn expand() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Inner(2.0, 1.0, 1.0)],
+            faces: vec![
+                FaceRule::AroundFace(vec![PointRef::new(&[], 0)]),
+                FaceRule::AroundVertex(vec![PointRef::new(&[], 0)]),
+                FaceRule::PerEdge(vec![
+                    PointRef::new(&[Step::Pair, Step::Next], 0), PointRef::new(&[Step::Pair], 0),
+                    PointRef::new(&[Step::Next], 0), PointRef::new(&[], 0)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    pub fn gyro() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Vertex, ChamberPoint::Face, EDGE_THIRD],
+            faces: vec![FaceRule::PerHalfEdge(vec![
+                PointRef::new(&[], 1), PointRef::new(&[], 2), PointRef::new(&[Step::Pair], 2),
+                PointRef::new(&[Step::Next], 0), PointRef::new(&[Step::Next], 2)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    pub fn snub() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Inner(1.0, 2.0, 2.0)],
+            faces: vec![
+                FaceRule::AroundFace(vec![PointRef::new(&[], 0)]),
+                FaceRule::AroundVertex(vec![PointRef::new(&[Step::Previous], 0)]),
+                FaceRule::PerHalfEdge(vec![
+                    PointRef::new(&[], 0), PointRef::new(&[Step::Previous], 0), PointRef::new(&[Step::Pair], 0)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    /// Every face becomes a smaller twisted copy, halfway between its center
+    /// and the edge thirds, surrounded by one hexagon per edge
+    pub fn whirl() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Vertex, EDGE_THIRD, ChamberPoint::Inner(2.0, 1.0, 3.0)],
+            faces: vec![
+                FaceRule::AroundFace(vec![PointRef::new(&[], 2)]),
+                FaceRule::PerHalfEdge(vec![
+                    PointRef::new(&[], 2), PointRef::new(&[], 1), PointRef::new(&[Step::Pair], 1),
+                    PointRef::new(&[Step::Next], 0), PointRef::new(&[Step::Next], 1), PointRef::new(&[Step::Next], 2)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    /// Every face becomes a twisted copy through its edge thirds, with a
+    /// quadrilateral blade for each edge
+    pub fn propeller() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Vertex, EDGE_THIRD],
+            faces: vec![
+                FaceRule::AroundFace(vec![PointRef::new(&[], 1)]),
+                FaceRule::PerHalfEdge(vec![
+                    PointRef::new(&[], 1), PointRef::new(&[Step::Pair], 1),
+                    PointRef::new(&[Step::Next], 0), PointRef::new(&[Step::Next], 1)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+
+    /// Every face becomes a smaller copy halfway between its center and the
+    /// edge midpoints, with a pentagon at every corner
+    pub fn quinto() -> ChamberPattern {
+        ChamberPattern {
+            points: vec![ChamberPoint::Vertex, ChamberPoint::Midpoint, ChamberPoint::Inner(1.0, 1.0, 2.0)],
+            faces: vec![
+                FaceRule::AroundFace(vec![PointRef::new(&[], 2)]),
+                FaceRule::PerHalfEdge(vec![
+                    PointRef::new(&[Step::Next], 0), PointRef::new(&[Step::Next], 1), PointRef::new(&[Step::Next], 2),
+                    PointRef::new(&[], 2), PointRef::new(&[], 1)])],
+            center: FaceCenterMethod::Centroid
+        }
+    }
+}
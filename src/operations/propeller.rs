@@ -0,0 +1,13 @@
+use super::{Propeller, Handedness, Operator, ChamberPattern, Mirrored};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Propeller for Mesh {
+    /// Builds the propeller: every face becomes a smaller twisted copy
+    /// with a quadrilateral blade for each of its edges. Chiral.
+    fn compute(&self, handedness: Handedness) -> Result<Mesh, MeshError> {
+        match handedness {
+            Handedness::Right => ChamberPattern::propeller().apply(self),
+            Handedness::Left => Mirrored(ChamberPattern::propeller()).apply(self)
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use super::{Quinto, Operator, ChamberPattern};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Quinto for Mesh {
+    /// Builds the quinto: every face becomes a smaller copy through points
+    /// between its edge midpoints and its center, surrounded by one
+    /// pentagon per corner.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        ChamberPattern::quinto().apply(self)
+    }
+}
@@ -0,0 +1,13 @@
+use super::{Snub, Handedness, Operator, ChamberPattern, Mirrored};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Snub for Mesh {
+    /// Builds the snub: faces and vertices both become twisted faces with
+    /// two triangles filling the gap along every edge. Dual of gyro, chiral.
+    fn compute(&self, handedness: Handedness) -> Result<Mesh, MeshError> {
+        match handedness {
+            Handedness::Right => ChamberPattern::snub().apply(self),
+            Handedness::Left => Mirrored(ChamberPattern::snub()).apply(self)
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use super::Truncate;
+use super::super::geometry::{Mesh, MeshError, VertexIndex, FaceIndex, HalfEdgeIndex, HalfEdgeCollection, UnsetValue};
+
+// how close the ratio has to be to 0.5 for cuts to be welded
+const WELD_TOLERANCE: f64 = 1e-9;
+
+impl Truncate for Mesh {
+    /// Cuts off every vertex with `degree` outgoing half-edges, or every
+    /// vertex if no degree is given. The cut lies at `ratio` along each
+    /// outgoing edge. At 0.5 the cuts from both ends of an edge are welded
+    /// into its midpoint, so truncating every vertex gives ambo. Ratios outside
+    /// (0, 0.5] would put cuts onto vertices or past each other and are rejected.
+    fn compute(&self, degree: Option<usize>, ratio: f64) -> Result<Mesh, MeshError> {
+        if !(ratio > 0.0 && ratio <= 0.5) {
+            return Err(MeshError::RatioOutOfRange);
+        }
+
+        let mut truncated = Mesh::new();
+
+        // kept vertices map to themselves, cut vertices to one new vertex per outgoing half-edge
+        let mut kept = vec![VertexIndex::unset(); self.vertex_count()];
+        let mut cut = vec![VertexIndex::unset(); self.half_edge_count()];
+        let mut cut_vertices: Vec<Vec<HalfEdgeIndex>> = Vec::new();
+        for (i, kept_index) in kept.iter_mut().enumerate() {
+            let index = VertexIndex::new(i as u32);
+            let circulator: Vec<HalfEdgeIndex> = match self.vertex_circulator(index).and_then(Iterator::collect) {
+                Err(_) => continue, // isolated vertex or broken loop
+                Ok(circulator) => circulator
+            };
+
+            let is_boundary = circulator.iter().any(|edge| self.half_edge(*edge).adjacent_face.is_unset());
+            let matches = degree.is_none() || degree == Some(circulator.len());
+            if is_boundary || !matches {
+                *kept_index = truncated.add_vertex_position(self.vertex(index).location);
+                continue;
+            }
+
+            let location = self.vertex(index).location;
+            for edge_index in &circulator {
+                let welded = cut[HalfEdgeCollection::edge_pair_index(*edge_index).index as usize];
+                if (ratio - 0.5).abs() < WELD_TOLERANCE && !welded.is_unset() {
+                    cut[edge_index.index as usize] = welded;
+                    continue;
+                }
+
+                let end = self.vertex(self.find_end_vertex_index(*edge_index)).location;
+                let position = location.lerp(end, ratio);
+                cut[edge_index.index as usize] = truncated.add_vertex_position(position);
+            }
+            cut_vertices.push(circulator);
+        }
+
+        // original faces, every cut corner is replaced by two vertices
+        for i in 0..self.face_count() {
+            let edges: Vec<HalfEdgeIndex> = match self.face_circulator(FaceIndex::new(i as u32)).and_then(Iterator::collect) {
+                Err(_) => continue,
+                Ok(edges) => edges
+            };
+
+            let n = edges.len();
+            let mut face: Vec<VertexIndex> = Vec::with_capacity(2 * n);
+            for j in 0..n {
+                let outgoing = edges[j];
+                let start = self.half_edge(outgoing).start_vertex;
+                if !kept[start.index as usize].is_unset() {
+                    face.push(kept[start.index as usize]);
+                    continue;
+                }
+
+                let incoming = edges[(j + n - 1) % n];
+                face.push(cut[HalfEdgeCollection::edge_pair_index(incoming).index as usize]);
+                face.push(cut[outgoing.index as usize]);
+            }
+
+            // welded cuts show up twice in a row
+            face.dedup();
+            if face.len() > 1 && face.first() == face.last() {
+                face.pop();
+            }
+            truncated.add_face_by_indices(face)?;
+        }
+
+        // one face per cut vertex, the vertex circulator walks clockwise
+        for circulator in cut_vertices {
+            truncated.add_face_by_indices(circulator.iter().rev().map(|edge| cut[edge.index as usize]).collect())?;
+        }
+
+        Ok(truncated)
+    }
+}
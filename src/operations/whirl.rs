@@ -0,0 +1,13 @@
+use super::{Whirl, Handedness, Operator, ChamberPattern, Mirrored};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Whirl for Mesh {
+    /// Builds the whirl: every face becomes a smaller twisted copy,
+    /// surrounded by one hexagon per edge. Chiral.
+    fn compute(&self, handedness: Handedness) -> Result<Mesh, MeshError> {
+        match handedness {
+            Handedness::Right => ChamberPattern::whirl().apply(self),
+            Handedness::Left => Mirrored(ChamberPattern::whirl()).apply(self)
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use super::{Zip, Kis, Operator, ChamberPattern};
+use super::super::geometry::{Mesh, MeshError};
+
+impl Zip for Mesh {
+    /// Builds the zip, the dual of the kis: faces and vertices both become
+    /// faces with twice their degree and every edge is zipped to a new one.
+    fn compute(&self) -> Result<Mesh, MeshError> {
+        ChamberPattern::dual().apply(&Kis::compute(self, None, 0.0)?)
+    }
+}
@@ -37,7 +37,7 @@ pub mod vertex_tests {
         // Act
         mesh.add_vertex(v1);
         mesh.add_vertex(v2);
-        mesh.compact();
+        mesh.compact().unwrap();
 
         // Assert
         assert_eq!(mesh.vertex_count(), 0);
@@ -46,7 +46,7 @@ pub mod vertex_tests {
 
 #[cfg(test)]
 pub mod edge_tests {
-    use super::super::geometry::{Mesh, Vertex, Face, Point, VertexIndex, FaceIndex, HalfEdge, HalfEdgeIndex};
+    use super::super::geometry::{Mesh, Point, HalfEdgeIndex};
 
     #[test]
     fn can_find_edges() {
@@ -55,7 +55,7 @@ pub mod edge_tests {
         let v0 = mesh.add_vertex_position(Point::new());
         let v1 = mesh.add_vertex_position(Point::from_values(2.0, 0.0, 0.0));
         let v2 = mesh.add_vertex_position(Point::from_values(2.0, 2.0, 0.0));
-        mesh.add_face_by_indices(vec![v0, v1, v2]);
+        mesh.add_face_by_indices(vec![v0, v1, v2]).unwrap();
         
         // Assert
         println!("can_find_edges mesh is: {:?}", mesh);
@@ -72,7 +72,7 @@ pub mod edge_tests {
         let v0 = mesh.add_vertex_position(Point::new());
         let v1 = mesh.add_vertex_position(Point::from_values(2.0, 0.0, 0.0));
         let v2 = mesh.add_vertex_position(Point::from_values(2.0, 2.0, 0.0));
-        mesh.add_face_by_indices(vec![v0, v1, v2]);
+        mesh.add_face_by_indices(vec![v0, v1, v2]).unwrap();
 
         // Assert
         assert_eq!(mesh.find_end_vertex_index(HalfEdgeIndex::new(0)), v1);
@@ -96,15 +96,16 @@ pub mod edge_tests {
         let v0 = mesh.add_vertex_position(Point::new());
         let v1 = mesh.add_vertex_position(Point::from_values(2.0, 0.0, 0.0));
         let v2 = mesh.add_vertex_position(Point::from_values(2.0, 2.0, 0.0));
-        mesh.add_face_by_indices(vec![v0, v1, v2]);
+        mesh.add_face_by_indices(vec![v0, v1, v2]).unwrap();
 
         // Act
         println!("mesh for can_find_vertex_circulator is : {:#?}", mesh);
-        match mesh.get_vertex_circulator(v0) {
-            None => panic!("No vertex circulator found for v0!"),
-            Some(circulator) => {
-                println!("Circulator is {:#?}" ,circulator);
-                assert_eq!(circulator.len(), 2);
+        match mesh.vertex_circulator(v0) {
+            Err(_) => panic!("No vertex circulator found for v0!"),
+            Ok(circulator) => {
+                let outgoing: Vec<HalfEdgeIndex> = circulator.collect::<Result<_, _>>().unwrap();
+                println!("Circulator is {:#?}" ,outgoing);
+                assert_eq!(outgoing.len(), 2);
             }
         }
     }
@@ -112,7 +113,7 @@ pub mod edge_tests {
 
 #[cfg(test)]
 pub mod face_tests {
-    use super::super::geometry::{Mesh, Vertex, Face, Point, VertexIndex, FaceIndex};
+    use super::super::geometry::{Mesh, Point, FaceIndex};
 
     #[test]
     fn face_adding() {
@@ -124,7 +125,7 @@ pub mod face_tests {
         let v3 = mesh.add_vertex_position(Point::from_values(0.0, 2.0, 0.0));
 
         // Act
-        let f_index = mesh.add_face_by_indices(vec![v0, v1, v2, v3]);
+        let f_index = mesh.add_face_by_indices(vec![v0, v1, v2, v3]).unwrap();
 
         // Assert
         assert_eq!(FaceIndex::new(0), f_index);
@@ -134,8 +135,8 @@ pub mod face_tests {
 
         // Act
         println!("NOT compact mesh is: {:?}", mesh);
-        mesh.remove_face(FaceIndex::new(0));
-        mesh.compact();
+        mesh.remove_face(FaceIndex::new(0)).unwrap();
+        mesh.compact().unwrap();
 
         // Assert
         println!("Compact mesh is: {:?}", mesh);
@@ -154,8 +155,8 @@ pub mod face_tests {
         let v3 = mesh.add_vertex_position(Point::from_values(0.0, 2.0, 0.0));
 
         // Act
-        let f0 = mesh.add_face_by_indices(vec![v0, v1, v2]);
-        let f1 = mesh.add_face_by_indices(vec![v2, v3, v0]);
+        let f0 = mesh.add_face_by_indices(vec![v0, v1, v2]).unwrap();
+        let f1 = mesh.add_face_by_indices(vec![v2, v3, v0]).unwrap();
 
         // Assert
         println!("f0 and f1: {:?}, {:?}", f0, f1);
@@ -164,3 +165,1757 @@ pub mod face_tests {
         assert_eq!(mesh.half_edge_count(), 10);
     }
 }
+
+#[cfg(test)]
+pub mod dual_tests {
+    use super::super::geometry::{Mesh, Point, FaceIndex};
+    use super::super::operations::{Operator, ChamberPattern};
+
+    fn cube() -> Mesh {
+        let mut mesh = Mesh::new();
+        let v: Vec<_> = [
+            (-1.0, -1.0, -1.0), (1.0, -1.0, -1.0), (1.0, 1.0, -1.0), (-1.0, 1.0, -1.0),
+            (-1.0, -1.0, 1.0), (1.0, -1.0, 1.0), (1.0, 1.0, 1.0), (-1.0, 1.0, 1.0)]
+            .iter()
+            .map(|(x, y, z)| mesh.add_vertex_position(Point::from_values(*x, *y, *z)))
+            .collect();
+
+        mesh.add_face_by_indices(vec![v[0], v[3], v[2], v[1]]).unwrap();
+        mesh.add_face_by_indices(vec![v[4], v[5], v[6], v[7]]).unwrap();
+        mesh.add_face_by_indices(vec![v[0], v[1], v[5], v[4]]).unwrap();
+        mesh.add_face_by_indices(vec![v[2], v[3], v[7], v[6]]).unwrap();
+        mesh.add_face_by_indices(vec![v[1], v[2], v[6], v[5]]).unwrap();
+        mesh.add_face_by_indices(vec![v[3], v[0], v[4], v[7]]).unwrap();
+        mesh
+    }
+
+    // summed face normal dotted with the face centroid, positive if
+    // the face is wound counter-clockwise seen from outside
+    pub fn outward_winding(mesh: &Mesh, index: FaceIndex) -> f64 {
+        let points: Vec<Point> = mesh.face_vertices(index).unwrap()
+            .map(|vertex| mesh.vertex(vertex).location)
+            .collect();
+        let n = points.len();
+        let mut normal = Point::new();
+        let mut center = Point::new();
+        for i in 0..n {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            normal += a.cross(b);
+            center += a;
+        }
+        normal.dot(center)
+    }
+
+    // vertex, edge and face counts of a closed mesh, checks Euler and winding
+    pub fn assert_counts(mesh: &Mesh, v: usize, e: usize, f: usize) {
+        assert_eq!(mesh.vertex_count(), v);
+        assert_eq!(mesh.edges().count(), e);
+        assert_eq!(mesh.face_count(), f);
+        assert_eq!(mesh.vertex_count() + mesh.face_count(), mesh.edges().count() + 2);
+        for i in 0..mesh.face_count() {
+            assert!(outward_winding(mesh, FaceIndex::new(i as u32)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn dual_of_cube_is_octahedron() {
+        // Arrange
+        let cube = cube();
+
+        // Act
+        let octahedron = ChamberPattern::dual().apply(&cube).unwrap();
+
+        // Assert
+        assert_eq!(octahedron.vertex_count(), 6);
+        assert_eq!(octahedron.half_edge_count(), 24);
+        assert_eq!(octahedron.face_count(), 8);
+        for i in 0..octahedron.face_count() {
+            let index = FaceIndex::new(i as u32);
+            assert_eq!(octahedron.face_degree(index).unwrap(), 3);
+            assert!(outward_winding(&octahedron, index) > 0.0);
+        }
+    }
+
+    #[test]
+    fn dual_of_dual_restores_counts() {
+        // Arrange
+        let cube = cube();
+
+        // Act
+        let dual = ChamberPattern::dual();
+        let result = dual.apply(&dual.apply(&cube).unwrap()).unwrap();
+
+        // Assert
+        assert_eq!(result.vertex_count(), 8);
+        assert_eq!(result.half_edge_count(), 24);
+        assert_eq!(result.face_count(), 6);
+        for i in 0..result.face_count() {
+            assert!(outward_winding(&result, FaceIndex::new(i as u32)) > 0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod face_center_tests {
+    use super::super::geometry::{Mesh, Point, FaceIndex};
+    use super::super::operations::FaceCenterMethod;
+
+    fn unevenly_spaced_square() -> (Mesh, FaceIndex) {
+        let mut mesh = Mesh::new();
+        let v0 = mesh.add_vertex_position(Point::from_values(0.0, 0.0, 1.0));
+        let v1 = mesh.add_vertex_position(Point::from_values(1.0, 0.0, 1.0));
+        let v2 = mesh.add_vertex_position(Point::from_values(4.0, 0.0, 1.0));
+        let v3 = mesh.add_vertex_position(Point::from_values(4.0, 4.0, 1.0));
+        let v4 = mesh.add_vertex_position(Point::from_values(0.0, 4.0, 1.0));
+        let face = mesh.add_face_by_indices(vec![v0, v1, v2, v3, v4]).unwrap();
+        (mesh, face)
+    }
+
+    fn assert_close(a: Point, b: Point) {
+        assert!((a.x - b.x).abs() < 1e-9, "{:?} != {:?}", a, b);
+        assert!((a.y - b.y).abs() < 1e-9, "{:?} != {:?}", a, b);
+        assert!((a.z - b.z).abs() < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn centroid_averages_vertices() {
+        // Arrange
+        let (mesh, face) = unevenly_spaced_square();
+
+        // Act
+        let center = mesh.face_center(face, FaceCenterMethod::Centroid);
+
+        // Assert
+        assert_close(center, Point::from_values(1.8, 1.6, 1.0));
+    }
+
+    #[test]
+    fn area_centroid_ignores_vertex_spacing() {
+        // Arrange
+        let (mesh, face) = unevenly_spaced_square();
+
+        // Act
+        let center = mesh.face_center(face, FaceCenterMethod::AreaCentroid);
+
+        // Assert
+        assert_close(center, Point::from_values(2.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn tangent_center_is_closest_plane_point_to_origin() {
+        // Arrange
+        let (mesh, face) = unevenly_spaced_square();
+
+        // Act
+        let center = mesh.face_center(face, FaceCenterMethod::Tangent);
+
+        // Assert
+        assert_close(center, Point::from_values(0.0, 0.0, 1.0));
+    }
+}
+
+#[cfg(test)]
+pub mod seed_tests {
+    use super::super::geometry::{Mesh, FaceIndex, VertexIndex, HalfEdgeIndex, SeedScale, UnsetValue};
+    use super::dual_tests::assert_counts;
+
+    fn assert_seed(mesh: &Mesh, v: usize, e: usize, f: usize) {
+        assert_counts(mesh, v, e, f);
+        for i in 0..mesh.half_edge_count() {
+            assert!(!mesh.half_edge(HalfEdgeIndex::new(i as u32)).adjacent_face.is_unset());
+        }
+    }
+
+    #[test]
+    fn platonic_seeds_are_closed_and_outward() {
+        assert_seed(&Mesh::tetrahedron(SeedScale::UnitCircumradius), 4, 6, 4);
+        assert_seed(&Mesh::cube(SeedScale::UnitCircumradius), 8, 12, 6);
+        assert_seed(&Mesh::octahedron(SeedScale::UnitCircumradius), 6, 12, 8);
+        assert_seed(&Mesh::dodecahedron(SeedScale::UnitCircumradius), 20, 30, 12);
+        assert_seed(&Mesh::icosahedron(SeedScale::UnitCircumradius), 12, 30, 20);
+    }
+
+    #[test]
+    fn seeds_can_be_scaled_to_unit_edge() {
+        for mesh in [
+            Mesh::tetrahedron(SeedScale::UnitEdge),
+            Mesh::cube(SeedScale::UnitEdge),
+            Mesh::octahedron(SeedScale::UnitEdge),
+            Mesh::dodecahedron(SeedScale::UnitEdge),
+            Mesh::icosahedron(SeedScale::UnitEdge)] {
+            for i in 0..mesh.half_edge_count() {
+                let edge = HalfEdgeIndex::new(i as u32);
+                let a = mesh.vertex(mesh.half_edge(edge).start_vertex).location;
+                let b = mesh.vertex(mesh.find_end_vertex_index(edge)).location;
+                let length = ((b.x - a.x).powi(2) + (b.y - a.y).powi(2) + (b.z - a.z).powi(2)).sqrt();
+                assert!((length - 1.0).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn seeds_can_be_scaled_to_unit_circumradius() {
+        let mesh = Mesh::dodecahedron(SeedScale::UnitCircumradius);
+        for i in 0..mesh.vertex_count() {
+            let p = mesh.vertex(VertexIndex::new(i as u32)).location;
+            assert!(((p.x * p.x + p.y * p.y + p.z * p.z).sqrt() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    // every vertex of every face lies in the plane through its first three vertices
+    fn assert_planar(mesh: &Mesh) {
+        for i in 0..mesh.face_count() {
+            let points: Vec<_> = mesh.face_vertices(FaceIndex::new(i as u32)).unwrap()
+                .map(|vertex| mesh.vertex(vertex).location)
+                .collect();
+            let (a, b, c) = (points[0], points[1], points[2]);
+            let (u, w) = ((b.x - a.x, b.y - a.y, b.z - a.z), (c.x - a.x, c.y - a.y, c.z - a.z));
+            let normal = (u.1 * w.2 - u.2 * w.1, u.2 * w.0 - u.0 * w.2, u.0 * w.1 - u.1 * w.0);
+            for p in points {
+                let distance = normal.0 * (p.x - a.x) + normal.1 * (p.y - a.y) + normal.2 * (p.z - a.z);
+                assert!(distance.abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn parametric_seeds_are_closed_outward_and_planar() {
+        for n in 3..9 {
+            let meshes = [
+                (Mesh::prism(n, SeedScale::UnitEdge), 2 * n, 3 * n, n + 2),
+                (Mesh::antiprism(n, SeedScale::UnitEdge), 2 * n, 4 * n, 2 * n + 2),
+                (Mesh::pyramid(n, SeedScale::UnitEdge), n + 1, 2 * n, n + 1),
+                (Mesh::cupola(n, SeedScale::UnitEdge), 3 * n, 5 * n, 2 * n + 2),
+                (Mesh::anticupola(n, SeedScale::UnitEdge), 3 * n, 6 * n, 3 * n + 2)];
+            // larger pyramids and cupolae have no unit-edge solid, see below
+            for (mesh, v, e, f) in meshes.iter() {
+                if let Some(mesh) = mesh {
+                    assert_seed(mesh, *v, *e, *f);
+                    assert_planar(mesh);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parametric_seeds_without_unit_edge_solid_are_none() {
+        for n in 3..9 {
+            assert_eq!(Mesh::pyramid(n, SeedScale::UnitEdge).is_some(), n <= 5);
+            assert_eq!(Mesh::cupola(n, SeedScale::UnitEdge).is_some(), n <= 5);
+            assert_eq!(Mesh::anticupola(n, SeedScale::UnitEdge).is_some(), n <= 6);
+            assert!(Mesh::prism(n, SeedScale::UnitEdge).is_some());
+            assert!(Mesh::antiprism(n, SeedScale::UnitEdge).is_some());
+        }
+    }
+
+    #[test]
+    fn parametric_seeds_need_three_sides() {
+        assert!(Mesh::prism(2, SeedScale::UnitEdge).is_none());
+        assert!(Mesh::anticupola(0, SeedScale::UnitEdge).is_none());
+    }
+
+    #[test]
+    fn johnson_solids_are_available_by_number() {
+        assert_seed(&Mesh::johnson(1, SeedScale::UnitEdge).unwrap(), 5, 8, 5);
+        assert_seed(&Mesh::johnson(5, SeedScale::UnitEdge).unwrap(), 15, 25, 12);
+        assert!(Mesh::johnson(0, SeedScale::UnitEdge).is_none());
+        assert!(Mesh::johnson(6, SeedScale::UnitEdge).is_none());
+        assert!(Mesh::johnson(92, SeedScale::UnitEdge).is_none());
+    }
+}
+
+#[cfg(test)]
+pub mod conway_tests {
+    use super::super::conway::{parse, OperatorKind, Seed, EvaluateError};
+    use super::super::geometry::MeshError;
+
+    #[test]
+    fn parses_operators_and_seed() {
+        // Act
+        let expression = parse("dakD").unwrap();
+
+        // Assert
+        let kinds: Vec<OperatorKind> = expression.operators.iter().map(|o| o.kind).collect();
+        assert_eq!(kinds, vec![OperatorKind::Dual, OperatorKind::Ambo, OperatorKind::Kis]);
+        assert_eq!(expression.seed, Seed::Dodecahedron);
+    }
+
+    #[test]
+    fn parses_numeric_parameters() {
+        // Act
+        let expression = parse("k5t3P17").unwrap();
+
+        // Assert
+        assert_eq!(expression.operators[0].parameter, Some(5));
+        assert_eq!(expression.operators[1].parameter, Some(3));
+        assert_eq!(expression.operators[1].column, 3);
+        assert_eq!(expression.seed, Seed::Prism(17));
+    }
+
+    #[test]
+    fn reports_column_and_token_of_errors() {
+        let error = parse("dfD").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (2, "f"));
+
+        let error = parse("dkX").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (3, "X"));
+
+        let error = parse("DkD").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (2, "k"));
+
+        let error = parse("dP2").unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (3, "2"));
+
+        let error = parse("dk").unwrap_err();
+        assert_eq!(error.column, 3);
+    }
+
+    #[test]
+    fn evaluates_right_to_left() {
+        // Act, kis and dual do not commute
+        let kis_of_dual = parse("kdC").unwrap().evaluate().unwrap();
+        let dual_of_kis = parse("dkC").unwrap().evaluate().unwrap();
+
+        // Assert, kdC is the kis octahedron and dkC the truncated octahedron
+        assert_eq!((kis_of_dual.vertex_count(), kis_of_dual.face_count()), (14, 24));
+        assert_eq!((dual_of_kis.vertex_count(), dual_of_kis.face_count()), (24, 14));
+    }
+
+    #[test]
+    fn evaluation_rejects_unknown_parameters() {
+        let error = parse("d3C").unwrap().evaluate().unwrap_err();
+        assert!(matches!(error, EvaluateError::UnexpectedParameter(_)));
+
+        let error = parse("J6").unwrap().evaluate().unwrap_err();
+        assert_eq!(error, EvaluateError::UnsupportedSeed(Seed::Johnson(6)));
+    }
+    #[test]
+    fn mesh_errors_convert_into_evaluate_errors() {
+        // Act
+        let error: EvaluateError = MeshError::DegenerateFace.into();
+
+        // Assert
+        assert_eq!(error, EvaluateError::Mesh(MeshError::DegenerateFace));
+        assert_eq!(error.to_string(), "Operator failed: A face needs at least three distinct vertices");
+    }
+}
+
+#[cfg(test)]
+pub mod ambo_tests {
+    use super::super::geometry::{Mesh, FaceIndex, SeedScale};
+    use super::super::operations::Ambo;
+    use super::dual_tests::outward_winding;
+
+    #[test]
+    fn ambo_of_cube_is_cuboctahedron() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+
+        // Act
+        let cuboctahedron = Ambo::compute(&cube).unwrap();
+
+        // Assert
+        assert_eq!(cuboctahedron.vertex_count(), 12);
+        assert_eq!(cuboctahedron.half_edge_count(), 2 * 24);
+        assert_eq!(cuboctahedron.face_count(), 14);
+        let mut triangles = 0;
+        for i in 0..cuboctahedron.face_count() {
+            let index = FaceIndex::new(i as u32);
+            assert!(outward_winding(&cuboctahedron, index) > 0.0);
+            if cuboctahedron.face_degree(index).unwrap() == 3 {
+                triangles += 1;
+            }
+        }
+        assert_eq!(triangles, 8);
+    }
+
+    #[test]
+    fn ambo_of_dodecahedron_is_icosidodecahedron() {
+        // Act
+        let mesh = Ambo::compute(&Mesh::dodecahedron(SeedScale::UnitEdge)).unwrap();
+
+        // Assert
+        assert_eq!(mesh.vertex_count(), 30);
+        assert_eq!(mesh.half_edge_count(), 2 * 60);
+        assert_eq!(mesh.face_count(), 32);
+    }
+}
+
+#[cfg(test)]
+pub mod kis_tests {
+    use super::super::geometry::{Mesh, FaceIndex, VertexIndex, SeedScale};
+    use super::super::operations::Kis;
+    use super::super::conway::parse;
+    use super::dual_tests::outward_winding;
+
+    #[test]
+    fn kis_of_dodecahedron_is_pentakis_dodecahedron() {
+        // Act
+        let mesh = Kis::compute(&Mesh::dodecahedron(SeedScale::UnitEdge), None, 0.1).unwrap();
+
+        // Assert
+        assert_eq!(mesh.vertex_count(), 32);
+        assert_eq!(mesh.half_edge_count(), 2 * 90);
+        assert_eq!(mesh.face_count(), 60);
+        for i in 0..mesh.face_count() {
+            assert!(outward_winding(&mesh, FaceIndex::new(i as u32)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn kis_filters_by_face_degree() {
+        // Arrange
+        let prism = Mesh::prism(5, SeedScale::UnitEdge).unwrap();
+
+        // Act
+        let caps = Kis::compute(&prism, Some(5), 0.0).unwrap();
+        let sides = Kis::compute(&prism, Some(4), 0.0).unwrap();
+        let none = Kis::compute(&prism, Some(7), 0.0).unwrap();
+
+        // Assert
+        assert_eq!((caps.vertex_count(), caps.face_count()), (12, 15));
+        assert_eq!((sides.vertex_count(), sides.face_count()), (15, 22));
+        assert_eq!((none.vertex_count(), none.face_count()), (10, 7));
+    }
+
+    #[test]
+    fn kis_apex_is_raised_along_normal() {
+        // Act
+        let mesh = Kis::compute(&Mesh::cube(SeedScale::UnitEdge), Some(4), 0.5).unwrap();
+
+        // Assert
+        for i in 8..mesh.vertex_count() {
+            let apex = mesh.vertex(VertexIndex::new(i as u32)).location;
+            let distance = (apex.x * apex.x + apex.y * apex.y + apex.z * apex.z).sqrt();
+            assert!((distance - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn kis_filter_is_parsed_from_notation() {
+        let mesh = parse("k4P6").unwrap().evaluate().unwrap();
+        assert_eq!(mesh.face_count(), 2 + 6 * 4);
+    }
+}
+
+#[cfg(test)]
+pub mod truncate_tests {
+    use super::super::geometry::{Mesh, FaceIndex, VertexIndex, SeedScale, MeshError};
+    use super::super::operations::{Truncate, Kis, Ambo, Operator, ChamberPattern};
+    use super::super::conway::parse;
+    use super::dual_tests::outward_winding;
+
+    #[test]
+    fn truncate_of_icosahedron_is_truncated_icosahedron() {
+        // Act
+        let mesh = Truncate::compute(&Mesh::icosahedron(SeedScale::UnitEdge), None, 1.0 / 3.0).unwrap();
+
+        // Assert
+        assert_eq!(mesh.vertex_count(), 60);
+        assert_eq!(mesh.half_edge_count(), 2 * 90);
+        assert_eq!(mesh.face_count(), 32);
+        for i in 0..mesh.face_count() {
+            assert!(outward_winding(&mesh, FaceIndex::new(i as u32)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn truncate_matches_dual_kis_dual_counts() {
+        // Arrange
+        let seed = Mesh::cube(SeedScale::UnitEdge);
+
+        // Act
+        let direct = Truncate::compute(&seed, None, 0.25).unwrap();
+        let dual = ChamberPattern::dual();
+        let composed = dual.apply(&Kis::compute(&dual.apply(&seed).unwrap(), None, 0.0).unwrap()).unwrap();
+
+        // Assert
+        assert_eq!(direct.vertex_count(), composed.vertex_count());
+        assert_eq!(direct.half_edge_count(), composed.half_edge_count());
+        assert_eq!(direct.face_count(), composed.face_count());
+    }
+
+    #[test]
+    fn truncate_places_cuts_at_ratio() {
+        // Act
+        let mesh = Truncate::compute(&Mesh::cube(SeedScale::UnitEdge), None, 0.25).unwrap();
+
+        // Assert, every cut vertex is 0.25 away from a cube corner along an edge
+        for i in 0..mesh.vertex_count() {
+            let p = mesh.vertex(VertexIndex::new(i as u32)).location;
+            let mut coordinates = [p.x.abs(), p.y.abs(), p.z.abs()];
+            coordinates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert!((coordinates[0] - 0.25).abs() < 1e-9);
+            assert!((coordinates[1] - 0.5).abs() < 1e-9);
+            assert!((coordinates[2] - 0.5).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn truncate_at_half_welds_into_ambo() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+
+        // Act
+        let mesh = Truncate::compute(&cube, None, 0.5).unwrap();
+        let ambo = Ambo::compute(&cube).unwrap();
+
+        // Assert
+        assert_eq!(mesh.vertex_count(), ambo.vertex_count());
+        assert_eq!(mesh.half_edge_count(), ambo.half_edge_count());
+        assert_eq!(mesh.face_count(), ambo.face_count());
+        for i in 0..mesh.face_count() {
+            assert!(outward_winding(&mesh, FaceIndex::new(i as u32)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn truncate_filters_by_vertex_degree() {
+        // Arrange, the pyramid apex has degree 5, the base vertices degree 3
+        let pyramid = Mesh::pyramid(5, SeedScale::UnitEdge).unwrap();
+
+        // Act
+        let apex = Truncate::compute(&pyramid, Some(5), 0.25).unwrap();
+        let parsed = parse("t5Y5").unwrap().evaluate().unwrap();
+
+        // Assert
+        assert_eq!((apex.vertex_count(), apex.face_count()), (10, 7));
+        assert_eq!((parsed.vertex_count(), parsed.face_count()), (10, 7));
+    }
+
+    #[test]
+    fn ratio_outside_half_open_range_is_rejected() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+
+        // Act & Assert
+        for ratio in [0.0, 1.0, -0.2, 0.7, f64::NAN].iter() {
+            assert_eq!(Truncate::compute(&cube, None, *ratio).unwrap_err(), MeshError::RatioOutOfRange);
+        }
+        assert!(Truncate::compute(&cube, None, 0.5).is_ok());
+    }
+}
+
+#[cfg(test)]
+pub mod derived_operator_tests {
+    use super::super::geometry::{Mesh, Point, VertexIndex, SeedScale};
+    use super::super::operations::{Join, Ortho, Expand, Gyro, Snub, Handedness};
+    use super::super::conway::parse;
+    use super::dual_tests::assert_counts;
+
+    fn seeds() -> Vec<(Mesh, usize, usize, usize)> {
+        vec![
+            (Mesh::tetrahedron(SeedScale::UnitEdge), 4, 6, 4),
+            (Mesh::cube(SeedScale::UnitEdge), 8, 12, 6),
+            (Mesh::icosahedron(SeedScale::UnitEdge), 12, 30, 20)]
+    }
+
+    #[test]
+    fn join_counts() {
+        for (seed, v, e, f) in seeds() {
+            assert_counts(&Join::compute(&seed).unwrap(), v + f, 2 * e, e);
+        }
+    }
+
+    #[test]
+    fn ortho_counts() {
+        for (seed, v, e, f) in seeds() {
+            assert_counts(&Ortho::compute(&seed).unwrap(), v + e + f, 4 * e, 2 * e);
+        }
+    }
+
+    #[test]
+    fn expand_counts() {
+        for (seed, v, e, f) in seeds() {
+            assert_counts(&Expand::compute(&seed).unwrap(), 2 * e, 4 * e, v + e + f);
+        }
+    }
+
+    #[test]
+    fn gyro_counts() {
+        for (seed, v, e, f) in seeds() {
+            assert_counts(&Gyro::compute(&seed, Handedness::Right).unwrap(), v + 2 * e + f, 5 * e, 2 * e);
+            assert_counts(&Gyro::compute(&seed, Handedness::Left).unwrap(), v + 2 * e + f, 5 * e, 2 * e);
+        }
+    }
+
+    #[test]
+    fn snub_counts() {
+        for (seed, v, e, f) in seeds() {
+            assert_counts(&Snub::compute(&seed, Handedness::Right).unwrap(), 2 * e, 5 * e, v + 2 * e + f);
+            assert_counts(&Snub::compute(&seed, Handedness::Left).unwrap(), 2 * e, 5 * e, v + 2 * e + f);
+        }
+    }
+
+    #[test]
+    fn snub_handedness_gives_mirror_images() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+
+        // Act
+        let right = Snub::compute(&cube, Handedness::Right).unwrap();
+        let left = Snub::compute(&cube, Handedness::Left).unwrap();
+
+        // Assert, mirroring the right snub cube through the xy plane gives the
+        // left one, but not the right one itself
+        let mirrored = |a: Point, b: Point| {
+            (a.x - b.x).abs() < 1e-9 && (a.y - b.y).abs() < 1e-9 && (a.z + b.z).abs() < 1e-9
+        };
+        let right_points: Vec<_> = (0..right.vertex_count())
+            .map(|i| right.vertex(VertexIndex::new(i as u32)).location)
+            .collect();
+        let left_points: Vec<_> = (0..left.vertex_count())
+            .map(|i| left.vertex(VertexIndex::new(i as u32)).location)
+            .collect();
+        assert!(left_points.iter().all(|l| right_points.iter().any(|r| mirrored(*l, *r))));
+        assert!(!right_points.iter().all(|a| right_points.iter().any(|b| mirrored(*a, *b))));
+    }
+
+    #[test]
+    fn derived_operators_are_parsed_from_notation() {
+        let mesh = parse("sC").unwrap().evaluate().unwrap();
+        assert_eq!((mesh.vertex_count(), mesh.face_count()), (24, 38));
+
+        let mesh = parse("gjeoT").unwrap().evaluate().unwrap();
+        assert_eq!(mesh.vertex_count() + mesh.face_count(), mesh.edge_count() + 2);
+    }
+}
+
+#[cfg(test)]
+pub mod extended_operator_tests {
+    use super::super::geometry::{Mesh, MeshError, SeedScale, VertexIndex, FaceIndex};
+    use super::super::operations::{Chamfer, Bevel, Meta, Needle, Zip, Loft, Whirl, Propeller, Quinto, Handedness};
+    use super::super::operations::{Ambo, Truncate, Expand, Snub, Operator, ChamberPattern};
+    use super::super::conway::parse;
+    use super::dual_tests::assert_counts;
+
+    fn platonic_seeds() -> Vec<(Mesh, usize, usize, usize)> {
+        vec![
+            (Mesh::tetrahedron(SeedScale::UnitEdge), 4, 6, 4),
+            (Mesh::cube(SeedScale::UnitEdge), 8, 12, 6),
+            (Mesh::octahedron(SeedScale::UnitEdge), 6, 12, 8),
+            (Mesh::dodecahedron(SeedScale::UnitEdge), 20, 30, 12),
+            (Mesh::icosahedron(SeedScale::UnitEdge), 12, 30, 20)]
+    }
+
+    // counts of the result from the seed counts
+    fn assert_operation_counts<F, C>(operation: F, counts: C)
+        where F: Fn(&Mesh) -> Result<Mesh, MeshError>, C: Fn(usize, usize, usize) -> (usize, usize, usize) {
+        for (seed, v, e, f) in platonic_seeds() {
+            let (v, e, f) = counts(v, e, f);
+            assert_counts(&operation(&seed).unwrap(), v, e, f);
+        }
+    }
+
+    #[test]
+    fn chamfer_counts() {
+        assert_operation_counts(|mesh| Chamfer::compute(mesh, 0.25), |v, e, f| (v + 2 * e, 4 * e, f + e));
+    }
+
+    #[test]
+    fn bevel_counts() {
+        assert_operation_counts(Bevel::compute, |v, e, f| (4 * e, 6 * e, v + e + f));
+    }
+
+    #[test]
+    fn meta_counts() {
+        assert_operation_counts(Meta::compute, |v, e, f| (v + e + f, 6 * e, 4 * e));
+    }
+
+    #[test]
+    fn needle_counts() {
+        assert_operation_counts(Needle::compute, |v, e, f| (v + f, 3 * e, 2 * e));
+    }
+
+    #[test]
+    fn zip_counts() {
+        assert_operation_counts(Zip::compute, |v, e, f| (2 * e, 3 * e, v + f));
+    }
+
+    #[test]
+    fn loft_counts() {
+        assert_operation_counts(|mesh| Loft::compute(mesh, 0.1), |v, e, f| (v + 2 * e, 5 * e, f + 2 * e));
+    }
+
+    #[test]
+    fn whirl_counts() {
+        assert_operation_counts(|mesh| Whirl::compute(mesh, Handedness::Right), |v, e, f| (v + 4 * e, 7 * e, f + 2 * e));
+        assert_operation_counts(|mesh| Whirl::compute(mesh, Handedness::Left), |v, e, f| (v + 4 * e, 7 * e, f + 2 * e));
+    }
+
+    #[test]
+    fn propeller_counts() {
+        assert_operation_counts(|mesh| Propeller::compute(mesh, Handedness::Right), |v, e, f| (v + 2 * e, 5 * e, f + 2 * e));
+        assert_operation_counts(|mesh| Propeller::compute(mesh, Handedness::Left), |v, e, f| (v + 2 * e, 5 * e, f + 2 * e));
+    }
+
+    #[test]
+    fn quinto_counts() {
+        assert_operation_counts(Quinto::compute, |v, e, f| (v + 3 * e, 6 * e, f + 2 * e));
+    }
+
+    // cube with a degree-2 vertex in the middle of one edge, closed but not simple
+    fn cube_with_split_edge() -> Mesh {
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+        let mut mesh = Mesh::new();
+        for i in 0..cube.vertex_count() {
+            mesh.add_vertex_position(cube.vertex(VertexIndex::new(i as u32)).location);
+        }
+
+        let (a, b) = (VertexIndex::new(0), cube.find_end_vertex_index(cube.vertex_edges(VertexIndex::new(0)).unwrap().next().unwrap()));
+        let middle = mesh.add_vertex_position(cube.vertex(a).location.lerp(cube.vertex(b).location, 0.5));
+        for i in 0..cube.face_count() {
+            let vertices: Vec<VertexIndex> = cube.face_vertices(FaceIndex::new(i as u32)).unwrap().collect();
+            let mut face = Vec::new();
+            for (j, vertex) in vertices.iter().enumerate() {
+                face.push(*vertex);
+                let next = vertices[(j + 1) % vertices.len()];
+                if (*vertex, next) == (a, b) || (*vertex, next) == (b, a) {
+                    face.push(middle);
+                }
+            }
+            mesh.add_face_by_indices(face).unwrap();
+        }
+
+        mesh
+    }
+
+    #[test]
+    fn operators_return_errors_instead_of_panicking() {
+        // Arrange
+        let mesh = cube_with_split_edge();
+
+        // Act & Assert
+        assert!(Ambo::compute(&mesh).is_err());
+        assert!(Truncate::compute(&mesh, None, 0.25).is_err());
+        assert!(Expand::compute(&mesh).is_err());
+        assert!(Snub::compute(&mesh, Handedness::Right).is_err());
+        assert!(Needle::compute(&mesh).is_err());
+        assert!(Bevel::compute(&mesh).is_err());
+        assert_eq!(ChamberPattern::dual().apply(&mesh).unwrap_err(), MeshError::DegenerateFace);
+    }
+
+    #[test]
+    fn extended_operators_are_parsed_from_notation() {
+        for notation in ["cC", "bC", "mC", "nC", "zC", "lC", "wC", "pC", "qC"].iter() {
+            let mesh = parse(notation).unwrap().evaluate().unwrap();
+            assert_eq!(mesh.vertex_count() + mesh.face_count(), mesh.edge_count() + 2);
+        }
+    }
+
+    #[test]
+    fn default_loft_has_no_flat_side_faces() {
+        // Act
+        let mesh = parse("lC").unwrap().evaluate().unwrap();
+
+        // Assert, no face is degenerate and no two neighbours lie in one plane
+        for i in 0..mesh.face_count() {
+            assert!(mesh.face_area(FaceIndex::new(i as u32)) > 1e-6);
+        }
+        for edge in mesh.edges() {
+            let faces: Vec<FaceIndex> = mesh.edge_faces(edge.half_edge()).unwrap().collect();
+            assert!(mesh.face_normal(faces[0]).dot(mesh.face_normal(faces[1])) < 1.0 - 1e-9);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod pattern_tests {
+    use super::super::geometry::{Mesh, SeedScale, MeshError};
+    use super::super::operations::{Operator, ChamberPattern, ChamberPoint, FaceRule, PointRef, Step, PatternError};
+    use super::super::operations::{FaceCenterMethod, Kis, Truncate, Needle};
+    use super::dual_tests::assert_counts;
+
+    fn assert_same_counts(a: &Mesh, b: &Mesh) {
+        assert_counts(a, b.vertex_count(), b.edges().count(), b.face_count());
+    }
+
+    #[test]
+    fn patterns_match_parametrised_operations() {
+        for seed in [Mesh::tetrahedron(SeedScale::UnitEdge), Mesh::cube(SeedScale::UnitEdge)].iter() {
+            assert_same_counts(&ChamberPattern::kis().apply(seed).unwrap(), &Kis::compute(seed, None, 0.0).unwrap());
+            assert_same_counts(&ChamberPattern::truncate().apply(seed).unwrap(), &Truncate::compute(seed, None, 0.25).unwrap());
+        }
+    }
+
+    #[test]
+    fn operators_can_be_registered_by_data() {
+        // Arrange, needle as one triangle per half-edge
+        let needle = ChamberPattern::new(
+            vec![ChamberPoint::Vertex, ChamberPoint::Face],
+            vec![FaceRule::PerHalfEdge(vec![
+                PointRef::new(&[], 0), PointRef::new(&[Step::Pair], 1), PointRef::new(&[], 1)])],
+            FaceCenterMethod::Centroid).unwrap();
+        let seed = Mesh::dodecahedron(SeedScale::UnitEdge);
+
+        // Act
+        let mesh = needle.apply(&seed).unwrap();
+
+        // Assert
+        assert_same_counts(&mesh, &Needle::compute(&seed).unwrap());
+    }
+
+    #[test]
+    fn invalid_pattern_data_is_rejected() {
+        // Act
+        let edge = ChamberPattern::new(
+            vec![ChamberPoint::Vertex, ChamberPoint::Edge(0.5)],
+            vec![FaceRule::AroundFace(vec![PointRef::new(&[], 1)])],
+            FaceCenterMethod::Centroid);
+        let missing = ChamberPattern::new(
+            vec![ChamberPoint::Vertex],
+            vec![FaceRule::AroundFace(vec![PointRef::new(&[], 3)])],
+            FaceCenterMethod::Centroid);
+
+        // Assert
+        assert_eq!(edge, Err(PatternError::EdgeFraction(1)));
+        assert_eq!(missing, Err(PatternError::PointOutOfRange(3)));
+    }
+
+    #[test]
+    fn inner_weights_are_checked() {
+        // Arrange
+        let with_inner = |weights: (f64, f64, f64)| ChamberPattern::new(
+            vec![ChamberPoint::Inner(weights.0, weights.1, weights.2)],
+            vec![FaceRule::AroundFace(vec![PointRef::new(&[], 0)])],
+            FaceCenterMethod::Centroid);
+
+        // Act & Assert
+        assert_eq!(with_inner((0.0, 0.0, 0.0)), Err(PatternError::InnerWeights(0)));
+        assert_eq!(with_inner((1.0, -0.5, 0.5)), Err(PatternError::InnerWeights(0)));
+        assert_eq!(with_inner((f64::NAN, 1.0, 1.0)), Err(PatternError::InnerWeights(0)));
+        assert_eq!(with_inner((f64::INFINITY, 1.0, 1.0)), Err(PatternError::InnerWeights(0)));
+        assert!(with_inner((1.0, 1.0, 2.0)).is_ok());
+    }
+
+    #[test]
+    fn malformed_pattern_returns_error() {
+        // Arrange, every original face is built twice
+        let twice = ChamberPattern::new(
+            vec![ChamberPoint::Vertex],
+            vec![
+                FaceRule::AroundFace(vec![PointRef::new(&[], 0)]),
+                FaceRule::AroundFace(vec![PointRef::new(&[], 0)])],
+            FaceCenterMethod::Centroid).unwrap();
+
+        // Act
+        let result = twice.apply(&Mesh::cube(SeedScale::UnitEdge));
+
+        // Assert
+        assert!(matches!(result, Err(MeshError::NonManifoldEdge(_)) | Err(MeshError::NonManifoldVertex(_))));
+    }
+}
+
+#[cfg(test)]
+pub mod flag_tests {
+    use super::super::geometry::{Mesh, Point, FlagIndex, SeedScale, UnsetValue};
+
+    #[test]
+    fn cube_has_four_flags_per_edge() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+
+        // Act
+        let flags = cube.flag_graph();
+
+        // Assert
+        assert_eq!(flags.len(), 48);
+    }
+
+    #[test]
+    fn involutions_swap_one_element() {
+        // Arrange
+        let mesh = Mesh::dodecahedron(SeedScale::UnitEdge);
+
+        // Act
+        let flags = mesh.flag_graph();
+
+        // Assert
+        for i in 0..flags.len() {
+            let index = FlagIndex::new(i as u32);
+            let flag = flags.flag(index);
+            let s0 = flags.sigma0(index);
+            let s1 = flags.sigma1(index);
+            let s2 = flags.sigma2(index);
+
+            assert_eq!(flags.sigma0(s0), index);
+            assert_eq!(flags.sigma1(s1), index);
+            assert_eq!(flags.sigma2(s2), index);
+
+            assert!(flags[s0].vertex != flag.vertex && flags[s0].half_edge == flag.half_edge && flags[s0].face == flag.face);
+            assert!(flags[s1].vertex == flag.vertex && flags[s1].half_edge != flag.half_edge && flags[s1].face == flag.face);
+            assert!(flags[s2].vertex == flag.vertex && flags[s2].face != flag.face);
+
+            // σ0 and σ2 commute
+            assert_eq!(flags.sigma0(s2), flags.sigma2(s0));
+        }
+    }
+
+    #[test]
+    fn boundary_flags_have_no_face_swap() {
+        // Arrange
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex_position(Point::from_values(0.0, 0.0, 0.0));
+        let b = mesh.add_vertex_position(Point::from_values(1.0, 0.0, 0.0));
+        let c = mesh.add_vertex_position(Point::from_values(0.0, 1.0, 0.0));
+        mesh.add_face_by_indices(vec![a, b, c]).unwrap();
+
+        // Act
+        let flags = mesh.flag_graph();
+
+        // Assert
+        assert_eq!(flags.len(), 6);
+        for i in 0..flags.len() {
+            assert!(flags.sigma2(FlagIndex::new(i as u32)).is_unset());
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod point_tests {
+    use super::super::geometry::{Point, Vector};
+
+    #[test]
+    fn arithmetic_operators() {
+        // Arrange
+        let a = Point::from_values(1.0, 2.0, 3.0);
+        let b = Point::from_values(4.0, -1.0, 0.5);
+
+        // Act
+        let sum = a + b;
+        let difference = a - b;
+        let scaled = a * 2.0;
+        let divided = a / 2.0;
+        let negated = -a;
+
+        // Assert
+        assert!(sum.approx_eq(Point::from_values(5.0, 1.0, 3.5), 1e-12));
+        assert!(difference.approx_eq(Point::from_values(-3.0, 3.0, 2.5), 1e-12));
+        assert!(scaled.approx_eq(Point::from_values(2.0, 4.0, 6.0), 1e-12));
+        assert!(divided.approx_eq(Point::from_values(0.5, 1.0, 1.5), 1e-12));
+        assert!(negated.approx_eq(Point::from_values(-1.0, -2.0, -3.0), 1e-12));
+    }
+
+    #[test]
+    fn products_and_lengths() {
+        // Arrange
+        let x: Vector = Point::from_values(3.0, 0.0, 0.0);
+        let y: Vector = Point::from_values(0.0, 4.0, 0.0);
+
+        // Act
+        let cross = x.cross(y);
+
+        // Assert
+        assert_eq!(x.dot(y), 0.0);
+        assert!(cross.approx_eq(Point::from_values(0.0, 0.0, 12.0), 1e-12));
+        assert_eq!(x.distance(y), 5.0);
+        assert!((cross.normalize().length() - 1.0).abs() < 1e-12);
+        assert!(Point::new().normalize().approx_eq(Point::new(), 0.0));
+        assert!(x.lerp(y, 0.25).approx_eq(Point::from_values(2.25, 1.0, 0.0), 1e-12));
+        assert!(!x.approx_eq(Point::from_values(3.1, 0.0, 0.0), 0.05));
+    }
+}
+
+#[cfg(test)]
+pub mod measure_tests {
+    use super::super::geometry::{Mesh, Point, FaceIndex, VertexIndex, SeedScale, Weighting};
+    use super::super::operations::FaceCenterMethod;
+
+    #[test]
+    fn cube_faces_are_unit_squares() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+
+        for i in 0..cube.face_count() {
+            let index = FaceIndex::new(i as u32);
+
+            // Act
+            let normal = cube.face_normal(index);
+            let center = cube.face_center(index, FaceCenterMethod::Centroid);
+
+            // Assert
+            assert!((cube.face_area(index) - 1.0).abs() < 1e-9);
+            assert!((normal.length() - 1.0).abs() < 1e-9);
+            assert!(normal.approx_eq(center * 2.0, 1e-9));
+            assert!(cube.face_planarity_error(index) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn non_planar_quad_has_planarity_error() {
+        // Arrange
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex_position(Point::from_values(0.0, 0.0, 0.0));
+        let b = mesh.add_vertex_position(Point::from_values(1.0, 0.0, 1.0));
+        let c = mesh.add_vertex_position(Point::from_values(1.0, 1.0, 0.0));
+        let d = mesh.add_vertex_position(Point::from_values(0.0, 1.0, 1.0));
+        let face = mesh.add_face_by_indices(vec![a, b, c, d]).unwrap();
+
+        // Act
+        let error = mesh.face_planarity_error(face);
+
+        // Assert
+        assert!((error - 0.5).abs() < 1e-9);
+        assert!(mesh.face_normal(face).approx_eq(Point::from_values(0.0, 0.0, 1.0), 1e-9));
+        assert!((mesh.face_area(face) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn planarity_error_uses_least_squares_plane() {
+        // Arrange, a long quad with one corner lifted
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex_position(Point::from_values(0.0, 0.0, 0.0));
+        let b = mesh.add_vertex_position(Point::from_values(4.0, 0.0, 0.0));
+        let c = mesh.add_vertex_position(Point::from_values(4.0, 1.0, 1.0));
+        let d = mesh.add_vertex_position(Point::from_values(0.0, 1.0, 0.0));
+        let face = mesh.add_face_by_indices(vec![a, b, c, d]).unwrap();
+
+        // Act
+        let error = mesh.face_planarity_error(face);
+
+        // Assert, the plane through the centroid along the Newell normal gives 2/9 instead
+        assert!((error - 0.263377045608861).abs() < 1e-9, "{}", error);
+    }
+
+    #[test]
+    fn cube_vertex_normals_point_outward() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+
+        for i in 0..cube.vertex_count() {
+            let index = VertexIndex::new(i as u32);
+            let expected = cube.vertex(index).location.normalize();
+
+            for weighting in [Weighting::Uniform, Weighting::Area, Weighting::Angle].iter() {
+                // Act
+                let normal = cube.vertex_normal(index, *weighting);
+
+                // Assert
+                assert!(normal.approx_eq(expected, 1e-9), "{:?} != {:?}", normal, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn boundary_vertex_normal_skips_missing_faces() {
+        // Arrange
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex_position(Point::from_values(0.0, 0.0, 0.0));
+        let b = mesh.add_vertex_position(Point::from_values(1.0, 0.0, 0.0));
+        let c = mesh.add_vertex_position(Point::from_values(0.0, 1.0, 0.0));
+        let d = mesh.add_vertex_position(Point::from_values(-1.0, 0.0, 1.0));
+        mesh.add_face_by_indices(vec![a, b, c]).unwrap();
+        mesh.add_face_by_indices(vec![a, c, d]).unwrap();
+
+        // Act
+        let uniform = mesh.vertex_normal(a, Weighting::Uniform);
+        let angle = mesh.vertex_normal(a, Weighting::Angle);
+
+        // Assert
+        let expected = (Point::from_values(0.0, 0.0, 1.0) + Point::from_values(1.0, 0.0, 1.0).normalize()).normalize();
+        assert!(uniform.approx_eq(expected, 1e-9));
+        assert!((angle.length() - 1.0).abs() < 1e-9);
+        assert!(mesh.vertex_normal(b, Weighting::Area).approx_eq(Point::from_values(0.0, 0.0, 1.0), 1e-9));
+    }
+}
+
+#[cfg(test)]
+pub mod mass_tests {
+    use super::super::geometry::{Mesh, Point, FaceIndex, VertexIndex, SeedScale, MeshError};
+
+    // rebuilds the mesh with every vertex moved by the offset
+    fn translated(mesh: &Mesh, offset: Point) -> Mesh {
+        let mut result = Mesh::new();
+        for i in 0..mesh.vertex_count() {
+            result.add_vertex_position(mesh.vertex(VertexIndex::new(i as u32)).location + offset);
+        }
+        for i in 0..mesh.face_count() {
+            let face: Vec<VertexIndex> = mesh.face_vertices(FaceIndex::new(i as u32)).unwrap().collect();
+            result.add_face_by_indices(face).unwrap();
+        }
+
+        result
+    }
+
+    #[test]
+    fn unit_cube_mass_properties() {
+        // Arrange
+        let offset = Point::from_values(2.0, -1.0, 0.5);
+        let cube = translated(&Mesh::cube(SeedScale::UnitEdge), offset);
+
+        // Act
+        let volume = cube.volume().unwrap();
+        let area = cube.surface_area().unwrap();
+        let centroid = cube.volume_centroid().unwrap();
+        let tensor = cube.inertia_tensor().unwrap();
+
+        // Assert
+        assert!((volume - 1.0).abs() < 1e-9);
+        assert!((area - 6.0).abs() < 1e-9);
+        assert!(centroid.approx_eq(offset, 1e-9));
+        for (i, row) in tensor.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 / 6.0 } else { 0.0 };
+                assert!((value - expected).abs() < 1e-9, "{:?}", tensor);
+            }
+        }
+    }
+
+    #[test]
+    fn regular_tetrahedron_volume() {
+        // Arrange
+        let tetrahedron = Mesh::tetrahedron(SeedScale::UnitEdge);
+
+        // Act
+        let volume = tetrahedron.volume().unwrap();
+
+        // Assert
+        assert!((volume - 1.0 / (6.0 * 2.0_f64.sqrt())).abs() < 1e-9);
+        assert!((tetrahedron.surface_area().unwrap() - 3.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn open_mesh_is_an_error() {
+        // Arrange
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex_position(Point::from_values(0.0, 0.0, 0.0));
+        let b = mesh.add_vertex_position(Point::from_values(1.0, 0.0, 0.0));
+        let c = mesh.add_vertex_position(Point::from_values(0.0, 1.0, 0.0));
+        mesh.add_face_by_indices(vec![a, b, c]).unwrap();
+
+        // Act
+        let volume = mesh.volume();
+
+        // Assert
+        assert!(matches!(volume, Err(MeshError::BoundaryEdge(_))));
+        assert!(mesh.surface_area().is_err());
+        assert!(mesh.volume_centroid().is_err());
+        assert!(mesh.inertia_tensor().is_err());
+    }
+}
+
+#[cfg(test)]
+pub mod canonical_tests {
+    use super::super::geometry::{Mesh, VertexIndex, SeedScale};
+    use super::super::conway::parse;
+
+    #[test]
+    fn canonical_cube_has_tangent_edges() {
+        // Arrange
+        let mut cube = Mesh::cube(SeedScale::UnitCircumradius);
+
+        // Act
+        let report = cube.canonicalize(1000, 1e-12);
+
+        // Assert
+        assert!(report.converged);
+        assert!(report.tangency_error < 1e-9);
+        assert!(report.centroid_error < 1e-9);
+        assert!(report.planarity_error < 1e-9);
+        for i in 0..cube.vertex_count() {
+            let location = cube.vertex(VertexIndex::new(i as u32)).location;
+            assert!((location.length() - 3.0_f64.sqrt() / 2.0_f64.sqrt()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn canonicalize_operator_output() {
+        // Arrange
+        let mut mesh = parse("tI").unwrap().evaluate().unwrap();
+
+        // Act
+        let report = mesh.canonicalize(2000, 1e-10);
+
+        // Assert
+        assert!(report.converged, "{:?}", report);
+        assert!(report.iterations > 1);
+        assert!(report.tangency_error < 1e-6, "{:?}", report);
+        assert!(report.centroid_error < 1e-6, "{:?}", report);
+        assert!(report.planarity_error < 1e-6, "{:?}", report);
+    }
+
+    #[test]
+    fn iteration_limit_is_reported() {
+        // Arrange
+        let mut mesh = parse("gC").unwrap().evaluate().unwrap();
+
+        // Act
+        let report = mesh.canonicalize(3, 1e-12);
+
+        // Assert
+        assert_eq!(report.iterations, 3);
+        assert!(!report.converged);
+    }
+}
+
+#[cfg(test)]
+pub mod relax_tests {
+    use super::super::geometry::{Mesh, FaceIndex, VertexIndex, SeedScale};
+    use super::super::conway::parse;
+
+    fn edge_lengths(mesh: &Mesh) -> Vec<f64> {
+        mesh.edges()
+            .map(|edge| mesh.edge_length(edge).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn planarize_flattens_distorted_cube() {
+        // Arrange
+        let mut mesh = Mesh::cube(SeedScale::UnitEdge);
+        let corner = mesh.vertex(VertexIndex::new(0)).location;
+        mesh.set_vertex_position(VertexIndex::new(0), corner * 1.2);
+        let max_error = |mesh: &Mesh| (0..mesh.face_count())
+            .map(|i| mesh.face_planarity_error(FaceIndex::new(i as u32)))
+            .fold(0.0, f64::max);
+        assert!(max_error(&mesh) > 1e-3);
+
+        // Act
+        let report = mesh.planarize(500, 1e-12);
+
+        // Assert
+        assert!(report.converged);
+        assert!(report.max_displacement < 1e-12);
+        assert!(max_error(&mesh) < 1e-9);
+    }
+
+    #[test]
+    fn equalize_edges_of_kis_cube() {
+        // Arrange
+        let mut mesh = parse("kC").unwrap().evaluate().unwrap();
+        let spread = |mesh: &Mesh| {
+            let lengths = edge_lengths(mesh);
+            lengths.iter().cloned().fold(0.0, f64::max) - lengths.iter().cloned().fold(f64::MAX, f64::min)
+        };
+        assert!(spread(&mesh) > 0.1);
+
+        // Act
+        let report = mesh.equalize_edges(1000, 1e-12);
+
+        // Assert
+        assert!(report.converged);
+        assert!(spread(&mesh) < 1e-6);
+    }
+
+    #[test]
+    fn pass_stops_after_step_count() {
+        // Arrange
+        let mut mesh = parse("kC").unwrap().evaluate().unwrap();
+
+        // Act
+        let report = mesh.equalize_edges(2, 1e-12);
+
+        // Assert
+        assert_eq!(report.iterations, 2);
+        assert!(!report.converged);
+    }
+}
+
+#[cfg(test)]
+pub mod sphere_tests {
+    use super::super::geometry::{Mesh, Point, FaceIndex, VertexIndex, SeedScale, MeshError};
+    use super::super::conway::parse;
+    use super::dual_tests::outward_winding;
+
+    #[test]
+    fn project_to_offset_sphere() {
+        // Arrange
+        let mut mesh = parse("kC").unwrap().evaluate().unwrap();
+        let center = Point::from_values(0.1, 0.0, -0.2);
+
+        // Act
+        mesh.project_to_sphere(2.0, center);
+
+        // Assert
+        for i in 0..mesh.vertex_count() {
+            let location = mesh.vertex(VertexIndex::new(i as u32)).location;
+            assert!((location.distance(center) - 2.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn reciprocal_of_cube_is_octahedron() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitCircumradius);
+
+        // Act
+        let octahedron = cube.reciprocal(1.0, Point::new()).unwrap();
+
+        // Assert
+        assert_eq!(octahedron.vertex_count(), 6);
+        assert_eq!(octahedron.face_count(), 8);
+        for i in 0..octahedron.vertex_count() {
+            let location = octahedron.vertex(VertexIndex::new(i as u32)).location;
+            assert!((location.length() - 3.0_f64.sqrt()).abs() < 1e-9);
+        }
+        for i in 0..octahedron.face_count() {
+            assert!(outward_winding(&octahedron, FaceIndex::new(i as u32)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn reciprocal_rejects_plane_through_center() {
+        // Arrange, the center lies on the face x = 0.5
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+        let center = Point::from_values(0.5, 0.1, 0.2);
+
+        // Act
+        let result = cube.reciprocal(1.0, center);
+
+        // Assert
+        assert!(matches!(result, Err(MeshError::PlaneThroughCenter(_))));
+    }
+
+    #[test]
+    fn reciprocal_skips_removed_faces() {
+        // Arrange, an open cube with its first face removed but not compacted
+        let mut cube = Mesh::cube(SeedScale::UnitCircumradius);
+        cube.remove_face(FaceIndex::new(0)).unwrap();
+
+        // Act
+        let result = cube.reciprocal(1.0, Point::new()).unwrap();
+
+        // Assert, only the four vertices away from the hole are closed
+        assert_eq!(result.vertex_count(), 5);
+        assert_eq!(result.face_count(), 4);
+        for i in 0..result.face_count() {
+            assert_eq!(result.face_degree(FaceIndex::new(i as u32)), Ok(3));
+        }
+    }
+
+    #[test]
+    fn reciprocating_twice_restores_the_mesh() {
+        // Arrange
+        let mesh = Mesh::dodecahedron(SeedScale::UnitEdge);
+        let center = Point::from_values(0.0, 0.1, 0.0);
+
+        // Act
+        let result = mesh.reciprocal(1.5, center).unwrap().reciprocal(1.5, center).unwrap();
+
+        // Assert
+        assert_eq!(result.vertex_count(), mesh.vertex_count());
+        for i in 0..mesh.vertex_count() {
+            let index = VertexIndex::new(i as u32);
+            let original = mesh.vertex(index).location;
+            let found = (0..result.vertex_count())
+                .any(|j| result.vertex(VertexIndex::new(j as u32)).location.approx_eq(original, 1e-9));
+            assert!(found, "{:?} missing", original);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod geodesic_tests {
+    use super::super::geometry::{Mesh, FaceIndex, VertexIndex, GeodesicSeed, MeshError};
+    use super::dual_tests::outward_winding;
+
+    fn assert_geodesic(seed: GeodesicSeed, faces: usize, m: usize, n: usize) {
+        // Act
+        let mesh = Mesh::geodesic(seed, m, n).unwrap();
+
+        // Assert
+        let t = m * m + m * n + n * n;
+        assert_eq!(mesh.face_count(), faces * t, "{:?} ({}, {})", seed, m, n);
+        assert_eq!(mesh.vertex_count(), faces * t / 2 + 2, "{:?} ({}, {})", seed, m, n);
+        assert_eq!(mesh.half_edge_count(), 3 * faces * t, "{:?} ({}, {})", seed, m, n);
+        assert!(mesh.find_boundary_half_edge().is_none());
+        for i in 0..mesh.vertex_count() {
+            assert!((mesh.vertex(VertexIndex::new(i as u32)).location.length() - 1.0).abs() < 1e-9);
+        }
+        for i in 0..mesh.face_count() {
+            assert!(outward_winding(&mesh, FaceIndex::new(i as u32)) > 0.0);
+        }
+    }
+
+    #[test]
+    fn geodesic_classes() {
+        for (m, n) in [(1, 0), (2, 0), (3, 0), (1, 1), (2, 2), (2, 1), (1, 2), (3, 1), (0, 2)].iter() {
+            assert_geodesic(GeodesicSeed::Icosahedron, 20, *m, *n);
+        }
+    }
+
+    #[test]
+    fn geodesic_octahedron_and_tetrahedron() {
+        for (m, n) in [(1, 0), (2, 0), (1, 1), (2, 1)].iter() {
+            assert_geodesic(GeodesicSeed::Octahedron, 8, *m, *n);
+            assert_geodesic(GeodesicSeed::Tetrahedron, 4, *m, *n);
+        }
+    }
+
+    #[test]
+    fn goldberg_has_twelve_pentagons() {
+        // Act
+        let mesh = Mesh::goldberg(2, 1).unwrap();
+
+        // Assert
+        let degrees: Vec<usize> = (0..mesh.face_count())
+            .map(|i| mesh.face_degree(FaceIndex::new(i as u32)).unwrap())
+            .collect();
+        assert_eq!(degrees.iter().filter(|d| **d == 5).count(), 12);
+        assert_eq!(degrees.iter().filter(|d| **d == 6).count(), 60);
+        for i in 0..mesh.face_count() {
+            assert!(mesh.face_planarity_error(FaceIndex::new(i as u32)) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn zero_frequency_is_an_error() {
+        assert_eq!(Mesh::geodesic(GeodesicSeed::Icosahedron, 0, 0).unwrap_err(), MeshError::ZeroFrequency);
+        assert_eq!(Mesh::goldberg(0, 0).unwrap_err(), MeshError::ZeroFrequency);
+    }
+}
+
+#[cfg(test)]
+pub mod mesh_error_tests {
+    use super::super::geometry::{Mesh, Point, VertexIndex, Face, FaceIndex, HalfEdge, HalfEdgeIndex, MeshError, UnsetValue};
+
+    fn square() -> (Mesh, Vec<VertexIndex>) {
+        let mut mesh = Mesh::new();
+        let v = vec![
+            mesh.add_vertex_position(Point::from_values(0.0, 0.0, 0.0)),
+            mesh.add_vertex_position(Point::from_values(1.0, 0.0, 0.0)),
+            mesh.add_vertex_position(Point::from_values(1.0, 1.0, 0.0)),
+            mesh.add_vertex_position(Point::from_values(0.0, 1.0, 0.0)),
+        ];
+
+        (mesh, v)
+    }
+
+    #[test]
+    fn degenerate_faces_are_rejected() {
+        // Arrange
+        let (mut mesh, v) = square();
+
+        // Act
+        let two = mesh.add_face_by_indices(vec![v[0], v[1]]);
+        let repeated = mesh.add_face_by_indices(vec![v[0], v[1], v[0]]);
+
+        // Assert
+        assert_eq!(two, Err(MeshError::DegenerateFace));
+        assert_eq!(repeated, Err(MeshError::DegenerateFace));
+        assert_eq!(mesh.face_count(), 0);
+        assert_eq!(mesh.half_edge_count(), 0);
+    }
+
+    #[test]
+    fn out_of_range_vertex_is_an_error() {
+        // Arrange
+        let (mut mesh, v) = square();
+
+        // Act
+        let result = mesh.add_face_by_indices(vec![v[0], v[1], VertexIndex::new(7)]);
+
+        // Assert
+        assert_eq!(result, Err(MeshError::IndexOutOfRange(7)));
+        assert_eq!(mesh.vertex_circulator(VertexIndex::new(7)).map(|_| ()), Err(MeshError::IndexOutOfRange(7)));
+        assert_eq!(mesh.face_circulator(FaceIndex::new(0)).map(|_| ()), Err(MeshError::IndexOutOfRange(0)));
+    }
+
+    #[test]
+    fn second_face_on_a_half_edge_is_non_manifold() {
+        // Arrange
+        let (mut mesh, v) = square();
+        mesh.add_face_by_indices(vec![v[0], v[1], v[2]]).unwrap();
+
+        // Act
+        let result = mesh.add_face_by_indices(vec![v[0], v[1], v[3]]);
+
+        // Assert
+        assert_eq!(result, Err(MeshError::NonManifoldEdge(HalfEdgeIndex::new(0))));
+        assert_eq!(mesh.face_count(), 1);
+    }
+
+    #[test]
+    fn open_loop_is_reported() {
+        // Arrange
+        let (mut mesh, v) = square();
+        let first = mesh.add_half_edge(HalfEdge::new(v[0], FaceIndex::unset(), HalfEdgeIndex::new(2)));
+        mesh.add_half_edge(HalfEdge::new(v[1], FaceIndex::unset(), HalfEdgeIndex::unset()));
+        mesh.add_half_edge(HalfEdge::new(v[1], FaceIndex::unset(), HalfEdgeIndex::unset()));
+        mesh.add_half_edge(HalfEdge::new(v[2], FaceIndex::unset(), HalfEdgeIndex::unset()));
+        let face = mesh.add_face(Face::new(first));
+
+        // Act
+        let result: Result<Vec<HalfEdgeIndex>, MeshError> = mesh.face_circulator(face).unwrap().collect();
+
+        // Assert
+        assert_eq!(result, Err(MeshError::BrokenLoop(first)));
+        assert_eq!(mesh.face_degree(face), Err(MeshError::BrokenLoop(first)));
+        assert!(matches!(mesh.face_vertices(face), Err(MeshError::BrokenLoop(_))));
+    }
+    #[test]
+    fn isolated_vertex_has_no_circulator() {
+        // Arrange
+        let (mut mesh, v) = square();
+        mesh.add_face_by_indices(vec![v[0], v[1], v[2]]).unwrap();
+
+        // Act
+        let result = mesh.vertex_circulator(v[3]).map(|_| ());
+
+        // Assert
+        assert_eq!(result, Err(MeshError::IsolatedVertex(v[3])));
+    }
+
+    #[test]
+    fn removed_face_cannot_be_removed_again() {
+        // Arrange
+        let (mut mesh, v) = square();
+        let face = mesh.add_face_by_indices(vec![v[0], v[1], v[2]]).unwrap();
+        mesh.remove_face(face).unwrap();
+
+        // Act
+        let result = mesh.remove_face(face);
+
+        // Assert
+        assert_eq!(result, Err(MeshError::IndexOutOfRange(face.index)));
+    }
+
+    #[test]
+    fn compact_rejects_unpaired_half_edge() {
+        // Arrange
+        let (mut mesh, v) = square();
+        mesh.add_face_by_indices(vec![v[0], v[1], v[2]]).unwrap();
+        mesh.add_half_edge(HalfEdge::new(v[3], FaceIndex::unset(), HalfEdgeIndex::unset()));
+
+        // Act
+        let result = mesh.compact();
+
+        // Assert
+        assert_eq!(result, Err(MeshError::UnpairedHalfEdge(HalfEdgeIndex::new(6))));
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.half_edge_count(), 7);
+    }
+}
+
+#[cfg(test)]
+pub mod logging_tests {
+    use super::super::geometry::{Mesh, SeedScale};
+    use super::super::conway::parse;
+    use std::process::Command;
+
+    // The test harness captures stdout in process, so the check runs this
+    // test again in a child process with capturing disabled
+    #[test]
+    fn building_icosahedron_prints_nothing() {
+        if std::env::var("CONWAY_STDOUT_CHILD").is_ok() {
+            println!("<begin>");
+            let mut mesh = Mesh::icosahedron(SeedScale::UnitEdge);
+            mesh.compact().unwrap();
+            parse("tI").unwrap().evaluate().unwrap();
+            println!("<end>");
+            return;
+        }
+
+        // Arrange
+        let mut child = Command::new(std::env::current_exe().unwrap());
+        child.args(["tests::logging_tests::building_icosahedron_prints_nothing", "--exact", "--nocapture", "--test-threads=1"])
+            .env("CONWAY_STDOUT_CHILD", "1");
+
+        // Act
+        let output = child.output().unwrap();
+
+        // Assert
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let start = stdout.find("<begin>").expect("child test did not run") + "<begin>".len();
+        let end = stdout.find("<end>").unwrap();
+        assert_eq!(stdout[start..end].trim(), "");
+    }
+}
+
+#[cfg(test)]
+pub mod circulator_tests {
+    use super::super::geometry::{Mesh, Point, VertexIndex, Face, FaceIndex, HalfEdge, HalfEdgeIndex, MeshError, SeedScale, UnsetValue};
+
+    #[test]
+    fn circulates_faces_with_more_than_hundred_sides() {
+        // Arrange
+        let prism = Mesh::prism(120, SeedScale::UnitEdge).unwrap();
+
+        // Act
+        let degrees: Vec<usize> = (0..prism.face_count())
+            .map(|i| prism.face_circulator(FaceIndex::new(i as u32)).unwrap().count())
+            .collect();
+
+        // Assert
+        assert_eq!(degrees.iter().filter(|d| **d == 120).count(), 2);
+        assert_eq!(degrees.iter().filter(|d| **d == 4).count(), 120);
+    }
+
+    #[test]
+    fn vertex_circulator_is_lazy_and_closes() {
+        // Arrange
+        let mesh = Mesh::icosahedron(SeedScale::UnitEdge);
+
+        // Act
+        let mut circulator = mesh.vertex_circulator(VertexIndex::new(0)).unwrap();
+        let first = circulator.next().unwrap().unwrap();
+
+        // Assert
+        assert_eq!(mesh.half_edge(first).start_vertex, VertexIndex::new(0));
+        assert_eq!(circulator.count(), 4);
+    }
+
+    #[test]
+    fn broken_loop_yields_one_error() {
+        // Arrange
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex_position(Point::new());
+        let b = mesh.add_vertex_position(Point::from_values(1.0, 0.0, 0.0));
+        let first = mesh.add_half_edge(HalfEdge::new(a, FaceIndex::unset(), HalfEdgeIndex::new(1)));
+        mesh.add_half_edge(HalfEdge::new(b, FaceIndex::unset(), HalfEdgeIndex::new(1)));
+        let face = mesh.add_face(Face::new(first));
+
+        // Act
+        let items: Vec<_> = mesh.face_circulator(face).unwrap().collect();
+
+        // Assert
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0], Ok(first));
+        assert_eq!(items[2], Err(MeshError::BrokenLoop(first)));
+    }
+}
+
+#[cfg(test)]
+pub mod topology_tests {
+    use super::super::geometry::{Mesh, Point, VertexIndex, FaceIndex, HalfEdgeIndex, SeedScale, MeshError};
+
+    #[test]
+    fn cube_adjacency() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+        let vertex = VertexIndex::new(0);
+        let face = FaceIndex::new(0);
+
+        // Act
+        let faces: Vec<FaceIndex> = cube.vertex_faces(vertex).unwrap().collect();
+        let neighbours: Vec<VertexIndex> = cube.vertex_neighbours(vertex).unwrap().collect();
+        let vertices: Vec<VertexIndex> = cube.face_vertices(face).unwrap().collect();
+        let edges: Vec<HalfEdgeIndex> = cube.face_edges(face).unwrap().collect();
+
+        // Assert
+        assert_eq!(cube.vertex_degree(vertex), Ok(3));
+        assert_eq!(cube.face_degree(face), Ok(4));
+        assert_eq!(faces.len(), 3);
+        assert_eq!(neighbours.len(), 3);
+        for neighbour in neighbours {
+            let distance = cube.vertex(vertex).location.distance(cube.vertex(neighbour).location);
+            assert!((distance - 1.0).abs() < 1e-9);
+        }
+        for (vertex, edge) in vertices.iter().zip(edges.iter()) {
+            assert_eq!(cube.half_edge(*edge).start_vertex, *vertex);
+            assert_eq!(cube.edge_faces(*edge).unwrap().count(), 2);
+            assert_eq!(cube.opposite_vertex(*edge), Ok(None));
+        }
+    }
+
+    #[test]
+    fn boundary_and_triangle_queries() {
+        // Arrange
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex_position(Point::from_values(0.0, 0.0, 0.0));
+        let b = mesh.add_vertex_position(Point::from_values(1.0, 0.0, 0.0));
+        let c = mesh.add_vertex_position(Point::from_values(0.0, 1.0, 0.0));
+        let d = mesh.add_vertex_position(Point::from_values(5.0, 5.0, 0.0));
+        let face = mesh.add_face_by_indices(vec![a, b, c]).unwrap();
+        let edge = mesh.find_half_edge_index(a, b).unwrap();
+
+        // Act
+        let faces: Vec<FaceIndex> = mesh.edge_faces(edge).unwrap().collect();
+
+        // Assert
+        assert_eq!(faces, vec![face]);
+        assert_eq!(mesh.opposite_vertex(edge), Ok(Some(c)));
+        assert_eq!(mesh.vertex_faces(a).unwrap().count(), 1);
+        assert_eq!(mesh.vertex_degree(a), Ok(2));
+        assert_eq!(mesh.vertex_degree(d), Ok(0));
+        assert!(matches!(mesh.vertex_degree(VertexIndex::new(9)), Err(MeshError::IndexOutOfRange(9))));
+    }
+}
+
+#[cfg(test)]
+pub mod full_edge_tests {
+    use super::super::geometry::{Mesh, Point, EdgeIndex, HalfEdgeIndex, SeedScale, MeshError, UnsetValue};
+    use super::super::conway::parse;
+
+    #[test]
+    fn cube_edges() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+
+        // Act
+        let lengths: Vec<f64> = cube.edges().map(|edge| cube.edge_length(edge).unwrap()).collect();
+
+        // Assert
+        assert_eq!(cube.edge_count(), 12);
+        assert_eq!(lengths.len(), 12);
+        assert!(lengths.iter().all(|length| (length - 1.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn edge_endpoints_and_midpoint() {
+        // Arrange
+        let cube = Mesh::cube(SeedScale::UnitEdge);
+        let edge = EdgeIndex::new(3);
+
+        // Act
+        let (start, end) = cube.edge_endpoints(edge).unwrap();
+        let midpoint = cube.edge_midpoint(edge).unwrap();
+
+        // Assert
+        assert_eq!(start, cube.half_edge(edge.half_edge()).start_vertex);
+        assert_eq!(end, cube.find_end_vertex_index(edge.half_edge()));
+        assert!((midpoint.distance(cube.vertex(start).location) - 0.5).abs() < 1e-9);
+        assert!((midpoint.distance(cube.vertex(end).location) - 0.5).abs() < 1e-9);
+        assert!(matches!(cube.edge_length(EdgeIndex::new(12)), Err(MeshError::IndexOutOfRange(12))));
+    }
+
+    #[test]
+    fn half_edge_round_trip() {
+        // Arrange
+        let edge = EdgeIndex::new(5);
+
+        // Act
+        let from_even = EdgeIndex::from_half_edge(HalfEdgeIndex::new(10));
+        let from_odd = EdgeIndex::from_half_edge(HalfEdgeIndex::new(11));
+
+        // Assert
+        assert_eq!(edge.half_edge(), HalfEdgeIndex::new(10));
+        assert_eq!(from_even, edge);
+        assert_eq!(from_odd, edge);
+        assert!(EdgeIndex::from_half_edge(HalfEdgeIndex::unset()).is_unset());
+    }
+
+    #[test]
+    fn removed_edges_are_not_counted() {
+        // Arrange
+        let mut mesh = Mesh::new();
+        let a = mesh.add_vertex_position(Point::from_values(0.0, 0.0, 0.0));
+        let b = mesh.add_vertex_position(Point::from_values(1.0, 0.0, 0.0));
+        let c = mesh.add_vertex_position(Point::from_values(1.0, 1.0, 0.0));
+        let d = mesh.add_vertex_position(Point::from_values(0.0, 1.0, 0.0));
+        let face = mesh.add_face_by_indices(vec![a, b, c]).unwrap();
+        mesh.add_face_by_indices(vec![a, c, d]).unwrap();
+        let removed = EdgeIndex::from_half_edge(mesh.find_half_edge_index(a, b).unwrap());
+
+        // Act
+        mesh.remove_face(face).unwrap();
+
+        // Assert
+        assert_eq!(mesh.edge_count(), 3);
+        assert_eq!(mesh.edges().count(), 3);
+        assert_eq!(mesh.edge_midpoint(removed).unwrap_err(), MeshError::UnusedEdge(removed));
+        assert_eq!(mesh.edge_length(removed), Err(MeshError::UnusedEdge(removed)));
+    }
+
+    #[test]
+    fn euler_characteristic_with_edge_count() {
+        // Arrange
+        let meshes = ["aC", "jD", "tI", "oT"].iter().map(|notation| parse(notation).unwrap().evaluate().unwrap());
+
+        // Act & Assert
+        for mesh in meshes {
+            assert_eq!(mesh.vertex_count() + mesh.face_count(), mesh.edge_count() + 2);
+            assert_eq!(mesh.edges().count(), mesh.edge_count());
+        }
+    }
+}